@@ -0,0 +1,101 @@
+//! Benchmarks for every day's parser and both parts, run against fixed generated inputs so
+//! results are comparable between runs.
+//!
+//! Run with `cargo bench -p advent`, or `cargo bench -p advent -- day15` for a single day.
+
+use aoc_common::{Rng, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fmt::Write;
+
+fn letters(rng: &mut Rng, alphabet: &[u8], len: usize) -> String {
+    (0..len).map(|_| *rng.choose(alphabet) as char).collect()
+}
+
+fn day2_input(rng: &mut Rng) -> String {
+    let alphabet = b"abcdefghijklmnopqrstuvwxyz";
+    let mut ids = (0..250)
+        .map(|_| letters(rng, alphabet, 26))
+        .collect::<Vec<String>>();
+
+    let mut twin = ids[50].clone().into_bytes();
+    twin[13] = if twin[13] == b'z' { b'a' } else { twin[13] + 1 };
+    ids[200] = String::from_utf8(twin).unwrap();
+
+    ids.iter().map(|id| format!("{}\n", id)).collect()
+}
+
+fn day6_input(rng: &mut Rng) -> String {
+    (0..50)
+        .map(|_| format!("{}, {}\n", rng.range(0, 300), rng.range(0, 300)))
+        .collect()
+}
+
+fn day10_input(rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..200 {
+        let (x, y) = (rng.range(0, 60), rng.range(0, 10));
+        let (dx, dy) = (rng.range(-5, 6), rng.range(-5, 6));
+        let (px, py) = (x - dx * 1000, y - dy * 1000);
+        writeln!(input, "position=<{}, {}> velocity=<{}, {}>", px, py, dx, dy).unwrap();
+    }
+
+    input
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, input: &str) {
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input)).unwrap()));
+
+    let input = S::parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input)).unwrap()));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input)).unwrap()));
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    let generate =
+        |generator: fn(&mut Rng, usize) -> String, size| generator(&mut Rng::new(2018), size);
+
+    bench_day::<day1::Day1>(c, &generate(day1::generate, 1000));
+    bench_day::<day2::Day2>(c, &day2_input(&mut Rng::new(2018)));
+    bench_day::<day3::Day3>(c, &generate(day3::generate, 1000));
+    bench_day::<day4::Day4>(c, &generate(day4::generate, 200));
+    bench_day::<day5::Day5>(c, &generate(day5::generate, 2000));
+    bench_day::<day6::Day6>(c, &day6_input(&mut Rng::new(2018)));
+    bench_day::<day7::Day7>(c, &generate(day7::generate, 26));
+    bench_day::<day8::Day8>(c, &generate(day8::generate, 2000));
+    bench_day::<day9::Day9>(c, "30 players; last marble is worth 5807 points");
+    bench_day::<day10::Day10>(c, &day10_input(&mut Rng::new(2018)));
+    bench_day::<day11::Day11>(c, "18");
+    bench_day::<day12::Day12>(c, DAY12_INPUT);
+    bench_day::<day13::Day13>(c, &generate(day13::generate, 30));
+    bench_day::<day14::Day14>(c, "110201");
+    bench_day::<day15::Day15>(c, &generate(day15::generate, 24));
+    bench_day::<day16::Day16>(c, &generate(day16::generate, 800));
+}
+
+/// The example from the puzzle, which settles into steady growth quickly.
+const DAY12_INPUT: &str = "\
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #
+";
+
+criterion_group!(benches, days);
+criterion_main!(benches);
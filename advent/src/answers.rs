@@ -0,0 +1,140 @@
+use aoc_common::{Answer, Result};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Expected answers keyed by day and part, as written in an `answers.toml` file:
+///
+/// ```toml
+/// [day1]
+/// part1 = 585
+/// part2 = "83173"
+/// ```
+pub struct Answers {
+    expected: HashMap<(u8, u8), String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "PASS",
+            Status::Fail { .. } => "FAIL",
+            Status::Unknown => "UNKNOWN",
+        };
+
+        formatter.pad(status)
+    }
+}
+
+fn day_number(key: &str) -> Option<u8> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+fn part_number(key: &str) -> Option<u8> {
+    match key {
+        "part1" => Some(1),
+        "part2" => Some(2),
+        _ => None,
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers> {
+        let source = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Answers::parse(&source).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn parse(source: &str) -> Result<Answers> {
+        let table = source.parse::<Table>()?;
+
+        let mut expected = HashMap::new();
+        for (day_key, parts) in &table {
+            let day = day_number(day_key).ok_or_else(|| format!("unknown day `{}`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{}` must be a table of parts", day_key))?;
+
+            for (part_key, value) in parts {
+                let part = part_number(part_key)
+                    .ok_or_else(|| format!("unknown part `{}.{}`", day_key, part_key))?;
+                let value = match value {
+                    Value::String(value) => value.clone(),
+                    Value::Integer(value) => value.to_string(),
+                    _ => {
+                        let message =
+                            format!("`{}.{}` must be a string or integer", day_key, part_key);
+                        return Err(message.into());
+                    }
+                };
+
+                expected.insert((day, part), value);
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+
+    /// Compares an answer with the expected one, ignoring surrounding whitespace.
+    pub fn check(&self, day: u8, answer: &Answer) -> Status {
+        match self.expected.get(&(day, answer.part)) {
+            Some(expected) if expected.trim() == answer.value.trim() => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+            },
+            None => Status::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn answer(part: u8, value: &str) -> Answer {
+        Answer::new(part, &value.to_string(), Duration::default())
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers::parse("[day1]\npart1 = 3\npart2 = \"2\"\n").unwrap();
+        assert_eq!(answers.check(1, &answer(1, "3")), Status::Pass);
+        assert_eq!(
+            answers.check(1, &answer(2, "5")),
+            Status::Fail {
+                expected: "2".to_string()
+            }
+        );
+        assert_eq!(answers.check(2, &answer(1, "3")), Status::Unknown);
+    }
+
+    #[test]
+    fn multiline_answers_ignore_surrounding_whitespace() {
+        let answers = Answers::parse("[day10]\npart1 = \"\"\"\n#..#\n####\n\"\"\"\n").unwrap();
+        assert_eq!(answers.check(10, &answer(1, "#..#\n####")), Status::Pass);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_keys() {
+        let error = Answers::parse("[day1]\npart3 = 1\n").err().unwrap();
+        assert_eq!(error.to_string(), "unknown part `day1.part3`");
+
+        let error = Answers::parse("[first]\npart1 = 1\n").err().unwrap();
+        assert_eq!(error.to_string(), "unknown day `first`");
+
+        let error = Answers::parse("[day1]\npart1 = 1.5\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`day1.part1` must be a string or integer"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_common::{Frames, Report, Result, Rng};
+use std::io::BufRead;
+
+/// Parses a day's input from a reader and answers one or both parts.
+pub type Solver = fn(Box<dyn BufRead>, Option<u8>) -> Result<Report>;
+
+/// Parses a day's input from a reader and draws it as a sequence of images.
+pub type Renderer = fn(Box<dyn BufRead>, &mut Frames) -> Result<()>;
+
+/// Writes a random puzzle input for a day, along with the size to use when none is given.
+pub struct Generator {
+    pub generate: fn(&mut Rng, usize) -> String,
+    pub size: usize,
+}
+
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub generator: Option<Generator>,
+    pub render: Option<Renderer>,
+}
+
+pub static DAYS: [Day; 16] = [
+    Day {
+        number: 1,
+        solve: aoc_common::solve::<day1::Day1, _>,
+        generator: Some(Generator {
+            generate: day1::generate,
+            size: 1000,
+        }),
+        render: None,
+    },
+    Day {
+        number: 2,
+        solve: aoc_common::solve::<day2::Day2, _>,
+        generator: None,
+        render: None,
+    },
+    Day {
+        number: 3,
+        solve: aoc_common::solve::<day3::Day3, _>,
+        generator: Some(Generator {
+            generate: day3::generate,
+            size: 1300,
+        }),
+        render: Some(aoc_common::render::<day3::Day3, _>),
+    },
+    Day {
+        number: 4,
+        solve: aoc_common::solve::<day4::Day4, _>,
+        generator: Some(Generator {
+            generate: day4::generate,
+            size: 300,
+        }),
+        render: None,
+    },
+    Day {
+        number: 5,
+        solve: aoc_common::solve::<day5::Day5, _>,
+        generator: Some(Generator {
+            generate: day5::generate,
+            size: 50000,
+        }),
+        render: None,
+    },
+    Day {
+        number: 6,
+        solve: aoc_common::solve::<day6::Day6, _>,
+        generator: None,
+        render: None,
+    },
+    Day {
+        number: 7,
+        solve: aoc_common::solve::<day7::Day7, _>,
+        generator: Some(Generator {
+            generate: day7::generate,
+            size: 26,
+        }),
+        render: None,
+    },
+    Day {
+        number: 8,
+        solve: aoc_common::solve::<day8::Day8, _>,
+        generator: Some(Generator {
+            generate: day8::generate,
+            size: 2000,
+        }),
+        render: None,
+    },
+    Day {
+        number: 9,
+        solve: aoc_common::solve::<day9::Day9, _>,
+        generator: None,
+        render: None,
+    },
+    Day {
+        number: 10,
+        solve: aoc_common::solve::<day10::Day10, _>,
+        generator: None,
+        render: Some(aoc_common::render::<day10::Day10, _>),
+    },
+    Day {
+        number: 11,
+        solve: aoc_common::solve::<day11::Day11, _>,
+        generator: None,
+        render: None,
+    },
+    Day {
+        number: 12,
+        solve: aoc_common::solve::<day12::Day12, _>,
+        generator: None,
+        render: Some(aoc_common::render::<day12::Day12, _>),
+    },
+    Day {
+        number: 13,
+        solve: aoc_common::solve::<day13::Day13, _>,
+        generator: Some(Generator {
+            generate: day13::generate,
+            size: 30,
+        }),
+        render: Some(aoc_common::render::<day13::Day13, _>),
+    },
+    Day {
+        number: 14,
+        solve: aoc_common::solve::<day14::Day14, _>,
+        generator: None,
+        render: None,
+    },
+    Day {
+        number: 15,
+        solve: aoc_common::solve::<day15::Day15, _>,
+        generator: Some(Generator {
+            generate: day15::generate,
+            size: 32,
+        }),
+        render: Some(aoc_common::render::<day15::Day15, _>),
+    },
+    Day {
+        number: 16,
+        solve: aoc_common::solve::<day16::Day16, _>,
+        generator: Some(Generator {
+            generate: day16::generate,
+            size: 800,
+        }),
+        render: None,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,352 @@
+use std::env;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod answers;
+mod days;
+
+use crate::answers::{Answers, Status};
+use crate::days::{Day, Generator, Renderer};
+use aoc_common::{Answer, Format, Frames, Result, Rng, Source};
+
+const USAGE: &str =
+    "usage: advent run <DAY|all> [FILE|-] [--inputs DIR] [--part 1|2] [--answers FILE] \
+     [--format text|json] [--time] [--jobs N] [--verbose]
+       advent generate <DAY> [--seed N] [--size N]
+       advent render <DAY> [FILE|-] [--out DIR] [--scale N] [--frames N] [--verbose]";
+
+enum Command {
+    Run(Options),
+    Generate {
+        generator: &'static Generator,
+        seed: u64,
+        size: usize,
+    },
+    Render {
+        render: Renderer,
+        input: Source,
+        frames: Frames,
+        verbose: bool,
+    },
+}
+
+struct Options {
+    days: Vec<&'static Day>,
+    file: Option<Source>,
+    inputs: Option<PathBuf>,
+    part: Option<u8>,
+    answers: Option<Answers>,
+    format: Format,
+    time: bool,
+    verbose: bool,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command> {
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("generate") => parse_generate(args),
+        Some("render") => parse_render(args),
+        Some(command) => Err(format!("unknown command: {}", command).into()),
+        None => Err(USAGE.into()),
+    }
+}
+
+fn find_day(number: &str) -> Result<&'static Day> {
+    let day = number
+        .parse()
+        .ok()
+        .and_then(days::find)
+        .ok_or_else(|| format!("unknown day: {}", number))?;
+
+    Ok(day)
+}
+
+fn parse_generate<I: Iterator<Item = String>>(mut args: I) -> Result<Command> {
+    let mut day = None;
+    let mut seed = None;
+    let mut size = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed requires a number")?;
+                seed = Some(value.parse().map_err(|_| "--seed must be a number")?);
+            }
+            "--size" => {
+                let value = args.next().ok_or("--size requires a number")?;
+                size = Some(value.parse().map_err(|_| "--size must be a number")?);
+            }
+            _ if day.is_none() => day = Some(find_day(&arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let day = day.ok_or(USAGE)?;
+    let generator = day
+        .generator
+        .as_ref()
+        .ok_or_else(|| format!("day {} has no input generator", day.number))?;
+
+    Ok(Command::Generate {
+        generator,
+        seed: seed.unwrap_or(2018),
+        size: size.unwrap_or(generator.size),
+    })
+}
+
+fn parse_render<I: Iterator<Item = String>>(mut args: I) -> Result<Command> {
+    let mut day = None;
+    let mut file = None;
+    let mut out = None;
+    let mut scale = 4;
+    let mut limit = 1000;
+    let mut verbose = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => {
+                out = Some(PathBuf::from(
+                    args.next().ok_or("--out requires a directory")?,
+                ))
+            }
+            "--scale" => {
+                let value = args.next().ok_or("--scale requires a number")?;
+                scale = value.parse().map_err(|_| "--scale must be a number")?;
+            }
+            "--frames" => {
+                let value = args.next().ok_or("--frames requires a number")?;
+                limit = value.parse().map_err(|_| "--frames must be a number")?;
+            }
+            "--verbose" => verbose = true,
+            _ if day.is_none() => day = Some(find_day(&arg)?),
+            _ if file.is_none() => file = Some(arg),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let day = day.ok_or(USAGE)?;
+    let render = day
+        .render
+        .ok_or_else(|| format!("day {} has no renderer", day.number))?;
+    let out = out.unwrap_or_else(|| PathBuf::from("frames").join(format!("day{}", day.number)));
+
+    Ok(Command::Render {
+        render,
+        input: Source::from_arg(file),
+        frames: Frames::new(out, scale, limit),
+        verbose,
+    })
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<Options> {
+    let mut selection = None;
+    let mut file = None;
+    let mut inputs = None;
+    let mut part = None;
+    let mut answers = None;
+    let mut format = Format::Text;
+    let mut time = false;
+    let mut verbose = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--inputs" => {
+                let dir = args.next().ok_or("--inputs requires a directory")?;
+                inputs = Some(PathBuf::from(dir));
+            }
+            "--answers" => {
+                let file = args.next().ok_or("--answers requires a file")?;
+                answers = Some(Answers::load(Path::new(&file))?);
+            }
+            "--format" => {
+                format = args
+                    .next()
+                    .ok_or("--format requires text or json")?
+                    .parse()?;
+            }
+            "--time" => time = true,
+            "--verbose" => verbose = true,
+            "--jobs" => {
+                let value = args.next().ok_or("--jobs requires a number")?;
+                aoc_common::set_jobs(value.parse().map_err(|_| "--jobs must be a number")?)?;
+            }
+            "--part" => match args.next().as_deref() {
+                Some("1") => part = Some(1),
+                Some("2") => part = Some(2),
+                _ => return Err("--part must be 1 or 2".into()),
+            },
+            _ if selection.is_none() => selection = Some(arg),
+            _ if file.is_none() => file = Some(Source::from_arg(Some(arg))),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let days = match selection.as_deref() {
+        Some("all") => {
+            if file.is_some() {
+                return Err("use --inputs to give a directory when running all days".into());
+            }
+
+            days::DAYS.iter().collect()
+        }
+        Some(number) => vec![find_day(number)?],
+        None => return Err(USAGE.into()),
+    };
+
+    Ok(Options {
+        days,
+        file,
+        inputs,
+        part,
+        answers,
+        format,
+        time,
+        verbose,
+    })
+}
+
+/// Works out where `day` reads its input: FILE, the inputs directory, or stdin.
+fn open_input(day: &Day, options: &Options) -> Result<Box<dyn BufRead>> {
+    let source = match (&options.file, &options.inputs) {
+        (Some(file), _) => file.clone(),
+        (None, Some(dir)) => Source::File(dir.join(format!("day{}.txt", day.number))),
+        (None, None) if options.days.len() > 1 => {
+            Source::File(PathBuf::from("inputs").join(format!("day{}.txt", day.number)))
+        }
+        (None, None) => Source::Stdin,
+    };
+
+    source.open()
+}
+
+/// A short form of an answer for the summary table, which has no room for drawings.
+fn summarize(value: &str) -> String {
+    match value.trim().lines().count() {
+        0 | 1 => value.trim().to_string(),
+        lines => format!("<{} lines>", lines),
+    }
+}
+
+/// Prints each answer's status against `answers`, returning whether none of them failed.
+fn print_summary(results: &[(u8, Answer)], answers: &Answers) -> bool {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    println!("{:>3}  {:>4}  {:<7}  answer", "day", "part", "status");
+    for (day, answer) in results {
+        let status = answers.check(*day, answer);
+        let value = summarize(&answer.value);
+        match &status {
+            Status::Pass => passed += 1,
+            Status::Fail { .. } => failed += 1,
+            Status::Unknown => unknown += 1,
+        }
+
+        match &status {
+            Status::Fail { expected } => println!(
+                "{:>3}  {:>4}  {:<7}  {} (expected {})",
+                day,
+                answer.part,
+                status,
+                value,
+                summarize(expected)
+            ),
+            _ => println!("{:>3}  {:>4}  {:<7}  {}", day, answer.part, status, value),
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} unknown",
+        passed, failed, unknown
+    );
+
+    failed == 0
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Generate {
+            generator,
+            seed,
+            size,
+        }) => {
+            print!("{}", (generator.generate)(&mut Rng::new(seed), size));
+            return;
+        }
+        Ok(Command::Render {
+            render,
+            input,
+            mut frames,
+            verbose,
+        }) => {
+            aoc_common::init_logging(verbose);
+            match input.open().and_then(|input| render(input, &mut frames)) {
+                Ok(()) => println!(
+                    "wrote {} frames to {}",
+                    frames.written(),
+                    frames.dir().display()
+                ),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    aoc_common::init_logging(options.verbose);
+    let mut failed = false;
+    let mut results = vec![];
+    for day in &options.days {
+        let report = open_input(day, &options).and_then(|input| (day.solve)(input, options.part));
+
+        match report {
+            Ok(report) => {
+                let jsons = report.to_json(day.number);
+                let timings = report.timings();
+
+                for (answer, mut json) in report.answers.into_iter().zip(jsons) {
+                    match (options.format, &options.answers) {
+                        (Format::Json, answers) => {
+                            if let Some(answers) = answers {
+                                let status = answers.check(day.number, &answer);
+                                if let Status::Fail { expected } = &status {
+                                    json["expected"] = expected.clone().into();
+                                    failed = true;
+                                }
+                                json["status"] = status.to_string().into();
+                            }
+                            println!("{}", json);
+                        }
+                        (Format::Text, None) => println!("day {} {}", day.number, answer),
+                        (Format::Text, Some(_)) => results.push((day.number, answer)),
+                    }
+                }
+
+                if options.time && options.format == Format::Text {
+                    println!("day {} time: {}", day.number, timings);
+                }
+            }
+            Err(e) => {
+                eprintln!("day {}: {}", day.number, e);
+                failed = true;
+            }
+        }
+    }
+
+    if let (Format::Text, Some(answers)) = (options.format, &options.answers) {
+        if !print_summary(&results, answers) {
+            failed = true;
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::point::Point;
+use std::convert::Infallible;
+use std::ops::{Index, IndexMut};
+
+/// A dense, row-major 2D grid indexed by `Point<usize>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from a character map where every character maps to a cell.
+    ///
+    /// Lines shorter than the longest line are padded with `T::default()`.
+    pub fn from_chars<F>(input: &str, mut cell: F) -> Grid<T>
+    where
+        T: Default,
+        F: FnMut(Point<usize>, char) -> T,
+    {
+        match Grid::parse(input, |point, c| Ok::<T, Infallible>(cell(point, c))) {
+            Ok(grid) => grid,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Builds a grid from a character map, one row per line.
+    ///
+    /// Lines shorter than the longest line are padded with `T::default()`.
+    pub fn parse<F, E>(input: &str, mut cell: F) -> Result<Grid<T>, E>
+    where
+        T: Default,
+        F: FnMut(Point<usize>, char) -> Result<T, E>,
+    {
+        let lines = input.lines().collect::<Vec<&str>>();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+        let height = lines.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, line) in lines.iter().enumerate() {
+            let mut chars = line.chars();
+            for x in 0..width {
+                match chars.next() {
+                    Some(c) => cells.push(cell(Point(x, y), c)?),
+                    None => cells.push(T::default()),
+                }
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.offset(point).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every point in the grid, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point(x, y)))
+    }
+
+    /// Every cell alongside its point, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    fn offset(&self, Point(x, y): Point<usize>) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        self.get(point).expect("Point outside of grid")
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        self.get_mut(point).expect("Point outside of grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pads_short_lines() {
+        let grid = Grid::from_chars("#.#\n#", |_, c| c == '#');
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[true, false, true][..], &[true, false, false][..]]
+        );
+    }
+
+    #[test]
+    fn parse_reports_cell_errors() {
+        let result = Grid::<bool>::parse("..\n.x", |point, c| match c {
+            '.' => Ok(true),
+            _ => Err(point),
+        });
+        assert_eq!(result, Err(Point(1, 1)));
+    }
+
+    #[test]
+    fn get_outside_the_grid() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[Point(1, 1)] = 5;
+        assert_eq!(grid.get(Point(1, 1)), Some(&5));
+        assert_eq!(grid.get(Point(2, 0)), None);
+        assert_eq!(grid.iter().filter(|&(_, &n)| n == 5).count(), 1);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::grid::Grid;
+use crate::Result;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// A red, green and blue pixel.
+pub type Rgb = [u8; 3];
+
+/// A grid cell that can be written as a pixel of a binary Netpbm image.
+///
+/// `bool` cells make a PBM bitmap where `true` is black, `u8` cells make a PGM graymap and
+/// `Rgb` cells make a PPM pixmap.
+pub trait Pixel: Copy {
+    /// The file extension for images of this pixel type, such as `pbm`.
+    const EXTENSION: &'static str;
+
+    /// Writes the header, from the magic number up to and including the maximum value.
+    fn write_header<W: Write>(writer: &mut W, width: usize, height: usize) -> io::Result<()>;
+
+    /// Writes a single row of pixels.
+    fn write_row<W: Write>(writer: &mut W, row: &[Self]) -> io::Result<()>;
+}
+
+impl Pixel for bool {
+    const EXTENSION: &'static str = "pbm";
+
+    fn write_header<W: Write>(writer: &mut W, width: usize, height: usize) -> io::Result<()> {
+        writeln!(writer, "P4\n{} {}", width, height)
+    }
+
+    fn write_row<W: Write>(writer: &mut W, row: &[bool]) -> io::Result<()> {
+        let bytes = row
+            .chunks(8)
+            .map(|bits| {
+                bits.iter()
+                    .enumerate()
+                    .fold(0u8, |byte, (i, &bit)| byte | ((bit as u8) << (7 - i)))
+            })
+            .collect::<Vec<u8>>();
+        writer.write_all(&bytes)
+    }
+}
+
+impl Pixel for u8 {
+    const EXTENSION: &'static str = "pgm";
+
+    fn write_header<W: Write>(writer: &mut W, width: usize, height: usize) -> io::Result<()> {
+        writeln!(writer, "P5\n{} {}\n255", width, height)
+    }
+
+    fn write_row<W: Write>(writer: &mut W, row: &[u8]) -> io::Result<()> {
+        writer.write_all(row)
+    }
+}
+
+impl Pixel for Rgb {
+    const EXTENSION: &'static str = "ppm";
+
+    fn write_header<W: Write>(writer: &mut W, width: usize, height: usize) -> io::Result<()> {
+        writeln!(writer, "P6\n{} {}\n255", width, height)
+    }
+
+    fn write_row<W: Write>(writer: &mut W, row: &[Rgb]) -> io::Result<()> {
+        for pixel in row {
+            writer.write_all(pixel)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes `grid` as a Netpbm image with every cell drawn as a `scale` by `scale` square.
+pub fn write_image<T: Pixel, W: Write>(
+    writer: &mut W,
+    grid: &Grid<T>,
+    scale: usize,
+) -> io::Result<()> {
+    let scale = scale.max(1);
+    T::write_header(writer, grid.width() * scale, grid.height() * scale)?;
+    for row in grid.rows().take(grid.height()) {
+        let scaled = row
+            .iter()
+            .flat_map(|&pixel| std::iter::repeat_n(pixel, scale))
+            .collect::<Vec<T>>();
+        for _ in 0..scale {
+            T::write_row(writer, &scaled)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `grid` to the image file at `path`.
+pub fn save_image<T: Pixel>(path: &Path, grid: &Grid<T>, scale: usize) -> Result<()> {
+    let file = File::create(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut writer = BufWriter::new(file);
+    write_image(&mut writer, grid, scale)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// A numbered sequence of images written to a directory as `frame-0000.ppm` and so on.
+///
+/// Frames past the limit are dropped, so renderers can stop early by checking `is_full`.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    scale: usize,
+    limit: usize,
+    written: usize,
+}
+
+impl Frames {
+    pub fn new<P: Into<PathBuf>>(dir: P, scale: usize, limit: usize) -> Frames {
+        Frames {
+            dir: dir.into(),
+            scale,
+            limit,
+            written: 0,
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The number of frames written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.limit - self.written
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.written >= self.limit
+    }
+
+    /// Writes the next frame, creating the directory for the first one.
+    pub fn write<T: Pixel>(&mut self, grid: &Grid<T>) -> Result<()> {
+        if self.is_full() {
+            return Ok(());
+        }
+
+        if self.written == 0 {
+            fs::create_dir_all(&self.dir).map_err(|e| format!("{}: {}", self.dir.display(), e))?;
+        }
+
+        let name = format!("frame-{:04}.{}", self.written, T::EXTENSION);
+        save_image(&self.dir.join(name), grid, self.scale)?;
+        self.written += 1;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+
+    fn image<T: Pixel>(grid: &Grid<T>, scale: usize) -> Vec<u8> {
+        let mut bytes = vec![];
+        write_image(&mut bytes, grid, scale).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn bitmaps_pack_rows_into_bytes() {
+        let grid = Grid::from_chars("#........#\n.#........", |_, c| c == '#');
+        let mut expected = b"P4\n10 2\n".to_vec();
+        expected.extend_from_slice(&[0b1000_0000, 0b0100_0000, 0b0100_0000, 0b0000_0000]);
+        assert_eq!(image(&grid, 1), expected);
+    }
+
+    #[test]
+    fn scaling_repeats_rows_and_columns() {
+        let mut grid = Grid::new(2, 1, 0u8);
+        grid[Point(1, 0)] = 200;
+        let mut expected = b"P5\n4 2\n255\n".to_vec();
+        expected.extend_from_slice(&[0, 0, 200, 200, 0, 0, 200, 200]);
+        assert_eq!(image(&grid, 2), expected);
+    }
+
+    #[test]
+    fn pixmaps_write_each_channel() {
+        let grid = Grid::new(1, 1, [1u8, 2, 3]);
+        assert_eq!(image(&grid, 1), b"P6\n1 1\n255\n\x01\x02\x03".to_vec());
+    }
+
+    #[test]
+    fn frames_stop_at_the_limit() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir, 1, 2);
+        for _ in 0..3 {
+            frames.write(&Grid::new(1, 1, true)).unwrap();
+        }
+
+        assert!(frames.is_full());
+        assert!(dir.join("frame-0001.pbm").exists());
+        assert!(!dir.join("frame-0002.pbm").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
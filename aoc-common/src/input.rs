@@ -0,0 +1,39 @@
+use crate::Result;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+/// Where puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// A file named on the command line, with no name or `-` meaning stdin.
+    pub fn from_arg<S: Into<String>>(arg: Option<S>) -> Source {
+        match arg.map(Into::into) {
+            None => Source::Stdin,
+            Some(ref name) if name == "-" => Source::Stdin,
+            Some(name) => Source::File(PathBuf::from(name)),
+        }
+    }
+
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            Source::File(path) => {
+                let file = File::open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+
+    /// Reads the whole input into memory.
+    pub fn read_to_string(&self) -> Result<String> {
+        let mut input = String::new();
+        self.open()?.read_to_string(&mut input)?;
+        Ok(input)
+    }
+}
@@ -0,0 +1,105 @@
+use std::env;
+use std::error;
+use std::io::BufRead;
+use std::process;
+
+mod grid;
+mod image;
+mod input;
+mod logging;
+mod options;
+mod output;
+mod parallel;
+mod parse;
+mod point;
+mod rng;
+
+pub use crate::grid::Grid;
+pub use crate::image::{save_image, write_image, Frames, Pixel, Rgb};
+pub use crate::input::Source;
+pub use crate::logging::init_logging;
+pub use crate::options::{Options, FLAGS};
+pub use crate::output::{print_report, timed, Answer, Format, Output, Report};
+pub use crate::parallel::{jobs, par_map, set_jobs};
+pub use crate::parse::{
+    parse_lines, parse_lines_with, read_lines, read_lines_with, ParseError, Scanner,
+};
+pub use crate::point::{reading_order, Bounds, Coordinate, Point};
+pub use crate::rng::Rng;
+
+pub type Error = Box<dyn error::Error + Send + Sync>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A single day's puzzle: how to read its input and how to answer each part.
+pub trait Solution {
+    /// The day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    type Input;
+    type Part1: Output;
+    type Part2: Output;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses input straight from a reader.
+    ///
+    /// Days that parse line by line override this so large inputs are never held in memory whole.
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+/// A day whose puzzle state can be drawn as a sequence of images.
+pub trait Render: Solution {
+    fn render(input: &Self::Input, frames: &mut Frames) -> Result<()>;
+}
+
+/// Parses `reader` and draws the day's frames.
+pub fn render<S: Render, R: BufRead>(reader: R, frames: &mut Frames) -> Result<()> {
+    let input = S::parse_reader(reader)?;
+    S::render(&input, frames)
+}
+
+/// Parses `reader` and answers the requested part, or both parts when `part` is `None`.
+pub fn solve<S: Solution, R: BufRead>(reader: R, part: Option<u8>) -> Result<Report> {
+    let (input, parse_elapsed) = timed(|| S::parse_reader(reader));
+    let input = input?;
+    let mut answers = vec![];
+
+    if part != Some(2) {
+        let (answer, elapsed) = timed(|| S::part1(&input));
+        answers.push(Answer::new(1, &answer?, elapsed));
+    }
+
+    if part != Some(1) {
+        let (answer, elapsed) = timed(|| S::part2(&input));
+        answers.push(Answer::new(2, &answer?, elapsed));
+    }
+
+    Ok(Report {
+        parse_elapsed,
+        answers,
+    })
+}
+
+fn run<S: Solution>() -> Result<()> {
+    let usage = format!("day{} [FILE|-]", S::DAY);
+    let options = Options::parse(env::args().skip(1), &usage, |_, _| Ok(false))?;
+    let reader = Source::from_arg(options.file.clone()).open()?;
+    options.print(S::DAY, &solve::<S, _>(reader, None)?);
+
+    Ok(())
+}
+
+/// Entry point shared by the day binaries.
+pub fn main<S: Solution>() {
+    if let Err(e) = run::<S>() {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,17 @@
+use std::io::{self, IsTerminal};
+use tracing_subscriber::EnvFilter;
+
+/// Sends log events from the simulations to stderr.
+///
+/// `RUST_LOG` picks what is shown when it is set, as in `RUST_LOG=day15=debug`. Otherwise
+/// `verbose` shows debug events and above, and only warnings are shown without it.
+pub fn init_logging(verbose: bool) {
+    let level = if verbose { "debug" } else { "warn" };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    // A second call keeps the subscriber from the first, which only matters in tests.
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init();
+}
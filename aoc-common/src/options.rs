@@ -0,0 +1,106 @@
+use crate::logging::init_logging;
+use crate::output::{print_report, Format, Report};
+use crate::parallel::set_jobs;
+use crate::Result;
+use std::process;
+
+/// The flags every day binary takes, after its own in a usage line.
+pub const FLAGS: &str = "[--format text|json] [--time] [--verbose] [--jobs N]";
+
+/// The command line shared by the day binaries: an input file and how to print the answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub file: Option<String>,
+    pub format: Format,
+    pub time: bool,
+}
+
+impl Options {
+    /// Parses the shared flags in `args`, offering every other flag to `extra` along with the
+    /// rest of the arguments, for it to take a value from. `extra` returns whether it knew the
+    /// flag, and the first argument nobody knows is the input file.
+    ///
+    /// `--jobs` sizes the thread pool and `--verbose` starts logging, and `--help` prints
+    /// `usage`, followed by the shared flags, and exits.
+    pub fn parse<I, F>(args: I, usage: &str, mut extra: F) -> Result<Options>
+    where
+        I: IntoIterator<Item = String>,
+        F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool>,
+    {
+        let mut file = None;
+        let mut format = Format::Text;
+        let mut time = false;
+        let mut verbose = false;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    format = args
+                        .next()
+                        .ok_or("--format requires text or json")?
+                        .parse()?
+                }
+                "--time" => time = true,
+                "--verbose" => verbose = true,
+                "--jobs" => {
+                    let jobs = args.next().and_then(|jobs| jobs.parse().ok());
+                    set_jobs(jobs.ok_or("--jobs requires a number")?)?;
+                }
+                "--help" => {
+                    println!("usage: {} {}", usage, FLAGS);
+                    process::exit(0);
+                }
+                _ if extra(&arg, &mut args)? => {}
+                _ if file.is_none() => file = Some(arg),
+                _ => return Err(format!("unexpected argument: {}", arg).into()),
+            }
+        }
+
+        init_logging(verbose);
+        Ok(Options { file, format, time })
+    }
+
+    /// Prints a day's answers in the requested format.
+    pub fn print(&self, day: u8, report: &Report) {
+        print_report(day, report, self.format, self.time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_shared_flags() {
+        let options = Options::parse(args("input.txt --time --format json"), "", |_, _| Ok(false));
+        assert_eq!(
+            options.unwrap(),
+            Options {
+                file: Some("input.txt".to_string()),
+                format: Format::Json,
+                time: true,
+            }
+        );
+        assert!(Options::parse(args("--format"), "", |_, _| Ok(false)).is_err());
+        assert!(Options::parse(args("a b"), "", |_, _| Ok(false)).is_err());
+    }
+
+    #[test]
+    fn parse_offers_other_flags_to_the_day() {
+        let mut size = None;
+        let options = Options::parse(args("--size 3 - --time"), "", |flag, rest| {
+            if flag != "--size" {
+                return Ok(false);
+            }
+            size = rest.next();
+            Ok(true)
+        });
+        assert_eq!(options.unwrap().file, Some("-".to_string()));
+        assert_eq!(size, Some("3".to_string()));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::point::Point;
+use serde_json::{json, Value};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A puzzle answer: printed as text, and optionally described by structured data.
+pub trait Output: Display {
+    /// Data behind the answer for machine-readable output, such as the rows of a drawing.
+    fn data(&self) -> Option<Value> {
+        None
+    }
+}
+
+macro_rules! plain_output {
+    ($($t:ty),*) => {
+        $(impl Output for $t {})*
+    };
+}
+
+plain_output!(i16, i32, i64, isize, u8, u16, u32, u64, usize, String);
+
+impl<T: Display + Copy + Into<Value>> Output for Point<T> {
+    fn data(&self) -> Option<Value> {
+        let Point(x, y) = *self;
+        Some(json!({ "x": x.into(), "y": y.into() }))
+    }
+}
+
+/// How answers are printed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format: {}", source)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    pub elapsed: Duration,
+    pub data: Option<Value>,
+}
+
+impl Answer {
+    pub fn new<T: Output>(part: u8, answer: &T, elapsed: Duration) -> Answer {
+        Answer {
+            part,
+            value: answer.to_string(),
+            elapsed,
+            data: answer.data(),
+        }
+    }
+
+    /// The answer as a JSON object, with the elapsed time in microseconds.
+    pub fn to_json(&self, day: u8) -> Value {
+        let mut json = json!({
+            "day": day,
+            "part": self.part,
+            "answer": self.value,
+            "elapsed_us": self.elapsed.as_micros() as u64,
+        });
+        if let Some(data) = &self.data {
+            json["data"] = data.clone();
+        }
+
+        json
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.value.contains('\n') {
+            write!(formatter, "part {}:\n{}", self.part, self.value)
+        } else {
+            write!(formatter, "part {}: {}", self.part, self.value)
+        }
+    }
+}
+
+/// A day's answers, along with how long parsing its input took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Report {
+    /// How long each step took, as in `parse 52.10µs, part 1 3.10ms, part 2 5.00ms`.
+    pub fn timings(&self) -> String {
+        let mut timings = vec![format!("parse {:.2?}", self.parse_elapsed)];
+        for answer in &self.answers {
+            timings.push(format!("part {} {:.2?}", answer.part, answer.elapsed));
+        }
+
+        timings.join(", ")
+    }
+
+    /// One JSON object per answer, each also carrying the parse time in microseconds.
+    pub fn to_json(&self, day: u8) -> Vec<Value> {
+        let parse_elapsed = self.parse_elapsed.as_micros() as u64;
+        self.answers
+            .iter()
+            .map(|answer| {
+                let mut json = answer.to_json(day);
+                json["parse_elapsed_us"] = parse_elapsed.into();
+                json
+            })
+            .collect()
+    }
+}
+
+/// Runs `f`, returning its result along with how long it took.
+pub fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Prints a day's answers as text, followed by their timings if `time` is set, or as one
+/// JSON object per line.
+pub fn print_report(day: u8, report: &Report, format: Format, time: bool) {
+    match format {
+        Format::Text => {
+            for answer in &report.answers {
+                println!("{}", answer);
+            }
+
+            if time {
+                println!("time: {}", report.timings());
+            }
+        }
+        Format::Json => {
+            for json in report.to_json(day) {
+                println!("{}", json);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_to_json() {
+        let answer = Answer::new(1, &Point(7usize, 3), Duration::from_micros(1500));
+        assert_eq!(
+            answer.to_json(13),
+            json!({
+                "day": 13,
+                "part": 1,
+                "answer": "7,3",
+                "elapsed_us": 1500,
+                "data": { "x": 7, "y": 3 },
+            })
+        );
+    }
+
+    #[test]
+    fn plain_answers_have_no_data() {
+        let answer = Answer::new(2, &42usize, Duration::default());
+        assert_eq!(answer.to_json(1).get("data"), None);
+    }
+
+    #[test]
+    fn report_timings() {
+        let report = Report {
+            parse_elapsed: Duration::from_micros(52),
+            answers: vec![
+                Answer::new(1, &1usize, Duration::from_millis(3)),
+                Answer::new(2, &2usize, Duration::from_secs(1)),
+            ],
+        };
+        assert_eq!(
+            report.timings(),
+            "parse 52.00µs, part 1 3.00ms, part 2 1.00s"
+        );
+        assert_eq!(report.to_json(5)[1]["parse_elapsed_us"], 52);
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!(
+            "xml".parse::<Format>(),
+            Err("unknown format: xml".to_string())
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::Result;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Applies `f` to every item, spread over the thread pool when built with the `parallel`
+/// feature.
+///
+/// Results are in the same order as `items` either way, so answers don't depend on the mode.
+pub fn par_map<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        items.into_par_iter().map(f).collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.into_iter().map(f).collect()
+    }
+}
+
+/// How many tasks run at once: the size of the thread pool, or one without the `parallel`
+/// feature.
+pub fn jobs() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::current_num_threads()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+/// Sets the size of the thread pool, which has to happen before anything runs in parallel.
+pub fn set_jobs(jobs: usize) -> Result<()> {
+    if jobs == 0 {
+        return Err("--jobs must be at least 1".into());
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build_global()?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        if jobs > 1 {
+            Err("--jobs needs a build with the `parallel` feature".into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_map_keeps_the_order_of_items() {
+        let items = (0..1000).collect::<Vec<u32>>();
+        let squares = par_map(items, |n| n * n);
+        assert_eq!(squares, (0..1000).map(|n| n * n).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn set_jobs_rejects_zero() {
+        assert!(set_jobs(0).is_err());
+    }
+}
@@ -0,0 +1,352 @@
+use crate::Error;
+use std::error;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// A parse failure pointing at the offending token in the puzzle input.
+///
+/// Lines and columns are 1-based and columns count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new<S: Into<String>>(
+        line: usize,
+        column: usize,
+        expected: S,
+        found: Option<String>,
+    ) -> ParseError {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// Moves the error to `line`, for errors raised while parsing a single line.
+    pub fn on_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            formatter,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+
+        match &self.found {
+            Some(found) => write!(formatter, "`{}`", found),
+            None => write!(formatter, "end of line"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses every non-blank line of `input` as a `T`, tagging errors with their line number.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_with(input, str::parse)
+}
+
+/// Like `parse_lines`, but with a custom parser for each line.
+pub fn parse_lines_with<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+/// Parses every non-blank line read from `reader` as a `T`, one line at a time.
+pub fn read_lines<R, T>(reader: R) -> Result<Vec<T>, Error>
+where
+    R: BufRead,
+    T: FromStr<Err = ParseError>,
+{
+    read_lines_with(reader, str::parse)
+}
+
+/// Like `read_lines`, but with a custom parser for each line.
+pub fn read_lines_with<R, T, F>(reader: R, mut parse: F) -> Result<Vec<T>, Error>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    let mut items = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            items.push(parse(&line).map_err(|e| e.on_line(i + 1))?);
+        }
+    }
+
+    Ok(items)
+}
+
+/// A cursor over a single line of input that skips whitespace between tokens.
+pub struct Scanner<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(source: &'a str) -> Scanner<'a> {
+        Scanner {
+            source,
+            position: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// The 1-based character column of the next token.
+    pub fn column(&mut self) -> usize {
+        self.skip_whitespace();
+        self.source[..self.position].chars().count() + 1
+    }
+
+    /// An error at the next token saying what should have been there.
+    pub fn error<S: Into<String>>(&mut self, expected: S) -> ParseError {
+        let column = self.column();
+        let found = self.rest().split_whitespace().next().map(String::from);
+        ParseError::new(1, column, expected, found)
+    }
+
+    pub fn is_empty(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    /// Consumes `literal` if it comes next, reporting whether it did.
+    pub fn accept(&mut self, literal: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(literal) {
+            self.position += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes `literal`, which must come next.
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.accept(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}`", literal)))
+        }
+    }
+
+    /// Consumes an optionally signed integer described as `what` in errors.
+    pub fn integer<T: FromStr>(&mut self, what: &str) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let sign = rest.starts_with(['+', '-']) as usize;
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+
+        match rest[..sign + digits].parse() {
+            Ok(value) if digits > 0 => {
+                self.position += sign + digits;
+                Ok(value)
+            }
+            _ => Err(self.error(what)),
+        }
+    }
+
+    /// Consumes the next run of non-whitespace characters.
+    pub fn word(&mut self, what: &str) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if end == 0 {
+            Err(self.error(what))
+        } else {
+            self.position += end;
+            Ok(&rest[..end])
+        }
+    }
+
+    /// The next character, without consuming it.
+    pub fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    /// Consumes a single character.
+    pub fn character(&mut self, what: &str) -> Result<char, ParseError> {
+        self.skip_whitespace();
+        match self.rest().chars().next() {
+            Some(c) => {
+                self.position += c.len_utf8();
+                Ok(c)
+            }
+            None => Err(self.error(what)),
+        }
+    }
+
+    /// Succeeds only if nothing but whitespace is left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn scanner_reads_tokens_across_whitespace() {
+        let mut scanner = Scanner::new("#12 @  3,-4: word");
+        assert!(scanner.accept("#"));
+        assert_eq!(scanner.integer::<u16>("id"), Ok(12));
+        assert_eq!(scanner.expect("@"), Ok(()));
+        assert_eq!(scanner.integer::<i32>("x"), Ok(3));
+        assert_eq!(scanner.expect(","), Ok(()));
+        assert_eq!(scanner.integer::<i32>("y"), Ok(-4));
+        assert_eq!(scanner.character(":"), Ok(':'));
+        assert_eq!(scanner.peek(), Some('w'));
+        assert_eq!(scanner.word("word"), Ok("word"));
+        assert_eq!(scanner.end(), Ok(()));
+    }
+
+    #[test]
+    fn scanner_accepts_explicit_plus_sign() {
+        let mut scanner = Scanner::new("+7");
+        assert_eq!(scanner.integer::<i32>("change"), Ok(7));
+    }
+
+    #[test]
+    fn scanner_errors_point_at_the_next_token() {
+        let mut scanner = Scanner::new("abc  def");
+        scanner.expect("abc").unwrap();
+        assert_eq!(
+            scanner.integer::<u8>("number"),
+            Err(ParseError::new(1, 6, "number", Some("def".to_string())))
+        );
+    }
+
+    #[test]
+    fn scanner_rejects_trailing_input() {
+        let mut scanner = Scanner::new("1 2");
+        scanner.integer::<u8>("number").unwrap();
+        assert_eq!(
+            scanner.end(),
+            Err(ParseError::new(1, 3, "end of line", Some("2".to_string())))
+        );
+    }
+
+    #[test]
+    fn scanner_rejects_a_bare_sign() {
+        let mut scanner = Scanner::new("-");
+        assert!(scanner.integer::<i32>("number").is_err());
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let error = ParseError::new(3, 7, "`,`", Some("x".to_string()));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 7: expected `,`, found `x`"
+        );
+
+        let error = ParseError::new(1, 2, "number", None);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 2: expected number, found end of line"
+        );
+    }
+
+    #[test]
+    fn parse_lines_skips_blank_lines_and_tags_errors() {
+        assert_eq!(
+            parse_lines::<Digit>("1\n\n2\n"),
+            Ok(vec![Digit(1), Digit(2)])
+        );
+        assert_eq!(
+            parse_lines::<Digit>("1\n\nx\n"),
+            Err(ParseError::new(3, 1, "digit", Some("x".to_string())))
+        );
+    }
+
+    #[test]
+    fn read_lines_matches_parse_lines() {
+        let lines: Vec<Digit> = read_lines("1\n\n2\n".as_bytes()).unwrap();
+        assert_eq!(lines, vec![Digit(1), Digit(2)]);
+
+        let error = read_lines::<_, Digit>("1\n\nx\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected digit, found `x`"
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Digit(u8);
+
+    impl FromStr for Digit {
+        type Err = ParseError;
+
+        fn from_str(source: &str) -> Result<Self, Self::Err> {
+            let mut scanner = Scanner::new(source);
+            let digit = scanner.integer("digit")?;
+            scanner.end()?;
+
+            Ok(Digit(digit))
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn scanner_never_panics(source in "\\PC*") {
+            let mut scanner = Scanner::new(&source);
+            while !scanner.is_empty() {
+                let _ = scanner.integer::<i8>("number");
+                let _ = scanner.accept(",");
+                let _ = scanner.peek();
+                let _ = scanner.column();
+                if scanner.character("character").is_err() {
+                    break;
+                }
+            }
+        }
+
+        #[test]
+        fn integers_round_trip(n: i64) {
+            let source = format!("{:+}", n);
+            let mut scanner = Scanner::new(&source);
+            prop_assert_eq!(scanner.integer("number"), Ok(n));
+            prop_assert_eq!(scanner.end(), Ok(()));
+        }
+    }
+}
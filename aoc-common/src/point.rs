@@ -0,0 +1,244 @@
+use crate::parse::{ParseError, Scanner};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// An integer type that can be used as a point coordinate.
+pub trait Coordinate: Copy + Ord + Hash + Debug + Add<Output = Self> + Sub<Output = Self> {
+    /// Moves the coordinate by `delta`, or `None` if the result does not fit.
+    fn step(self, delta: isize) -> Option<Self>;
+}
+
+macro_rules! signed_coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            fn step(self, delta: isize) -> Option<Self> {
+                let delta = <$t as std::convert::TryFrom<isize>>::try_from(delta).ok()?;
+                self.checked_add(delta)
+            }
+        })*
+    };
+}
+
+macro_rules! unsigned_coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            fn step(self, delta: isize) -> Option<Self> {
+                let magnitude = <$t as std::convert::TryFrom<usize>>::try_from(delta.unsigned_abs()).ok()?;
+                if delta < 0 {
+                    self.checked_sub(magnitude)
+                } else {
+                    self.checked_add(magnitude)
+                }
+            }
+        })*
+    };
+}
+
+signed_coordinate!(i16, i32, i64, isize);
+unsigned_coordinate!(u8, u16, u32, u64, usize);
+
+/// A point on a 2D grid where `y` grows downwards.
+///
+/// Points are ordered in reading order: top-to-bottom, then left-to-right.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point<T>(pub T, pub T);
+
+impl<T: Coordinate> Point<T> {
+    pub fn manhattan_distance(self, Point(ox, oy): Point<T>) -> T {
+        let Point(sx, sy) = self;
+        (sx.max(ox) - sx.min(ox)) + (sy.max(oy) - sy.min(oy))
+    }
+
+    /// The point offset by `(dx, dy)`, or `None` if it falls outside the coordinate type.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Point<T>> {
+        let Point(x, y) = self;
+        Some(Point(x.step(dx)?, y.step(dy)?))
+    }
+
+    /// The four orthogonally adjacent points, in reading order.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point<T>> {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// The eight orthogonally and diagonally adjacent points, in reading order.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point<T>> {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ]
+        .iter()
+        .filter_map(move |&(dx, dy)| self.offset(dx, dy))
+    }
+}
+
+/// Compares two points top-to-bottom, then left-to-right.
+pub fn reading_order<T: Ord>(Point(sx, sy): &Point<T>, Point(ox, oy): &Point<T>) -> Ordering {
+    (sy, sx).cmp(&(oy, ox))
+}
+
+impl<T: Ord> Ord for Point<T> {
+    fn cmp(&self, other: &Point<T>) -> Ordering {
+        reading_order(self, other)
+    }
+}
+
+impl<T: Ord> PartialOrd for Point<T> {
+    fn partial_cmp(&self, other: &Point<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let Point(x, y) = self;
+        write!(formatter, "{},{}", x, y)
+    }
+}
+
+/// Parses points written as `x,y` with optional whitespace around each coordinate.
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(source);
+        let x = scanner.integer("x coordinate")?;
+        scanner.expect(",")?;
+        let y = scanner.integer("y coordinate")?;
+        scanner.end()?;
+
+        Ok(Point(x, y))
+    }
+}
+
+/// The smallest rectangle containing a set of points, inclusive of both corners.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bounds<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coordinate> Bounds<T> {
+    /// The bounding box of `points`, or `None` if there are none.
+    pub fn of<I: IntoIterator<Item = Point<T>>>(points: I) -> Option<Bounds<T>> {
+        points
+            .into_iter()
+            .fold(None, |bounds, point @ Point(x, y)| {
+                Some(match bounds {
+                    None => Bounds {
+                        min: point,
+                        max: point,
+                    },
+                    Some(Bounds {
+                        min: Point(sx, sy),
+                        max: Point(ex, ey),
+                    }) => Bounds {
+                        min: Point(x.min(sx), y.min(sy)),
+                        max: Point(x.max(ex), y.max(ey)),
+                    },
+                })
+            })
+    }
+
+    /// The distance between the left and right edges.
+    pub fn width(&self) -> T {
+        self.max.0 - self.min.0
+    }
+
+    /// The distance between the top and bottom edges.
+    pub fn height(&self) -> T {
+        self.max.1 - self.min.1
+    }
+
+    pub fn contains(&self, Point(x, y): Point<T>) -> bool {
+        self.min.0 <= x && x <= self.max.0 && self.min.1 <= y && y <= self.max.1
+    }
+
+    pub fn is_edge(&self, Point(x, y): Point<T>) -> bool {
+        x == self.min.0 || x == self.max.0 || y == self.min.1 || y == self.max.1
+    }
+
+    /// Every point inside the bounds, in reading order.
+    pub fn points(self) -> impl Iterator<Item = Point<T>> {
+        let Bounds { min, max } = self;
+        let rows = successors(min.1, max.1);
+        rows.flat_map(move |y| successors(min.0, max.0).map(move |x| Point(x, y)))
+    }
+}
+
+fn successors<T: Coordinate>(start: T, end: T) -> impl Iterator<Item = T> {
+    std::iter::successors(Some(start), move |&n| n.step(1)).take_while(move |&n| n <= end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn points_sort_in_reading_order() {
+        let mut points = vec![Point(2, 1), Point(1, 2), Point(3, 0), Point(0, 1)];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![Point(3, 0), Point(0, 1), Point(2, 1), Point(1, 2)]
+        );
+    }
+
+    #[test]
+    fn parse_point() {
+        assert_eq!("1, -6".parse(), Ok(Point(1, -6)));
+        assert_eq!(
+            "1 6".parse::<Point<isize>>(),
+            Err(ParseError::new(1, 3, "`,`", Some("6".to_string())))
+        );
+        assert_eq!(Point(1, -6).to_string(), "1,-6");
+    }
+
+    #[test]
+    fn manhattan_distance_of_unsigned_points() {
+        assert_eq!(Point(1usize, 8).manhattan_distance(Point(4, 2)), 9);
+    }
+
+    #[test]
+    fn neighbors_stay_inside_the_coordinate_type() {
+        let neighbors = Point(0usize, 0).neighbors4().collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![Point(1, 0), Point(0, 1)]);
+        assert_eq!(Point(5isize, 5).neighbors8().count(), 8);
+    }
+
+    #[test]
+    fn bounds_of_points() {
+        let bounds = Bounds::of(vec![Point(1, 6), Point(8, 3), Point(3, 9)]).unwrap();
+        assert_eq!(bounds.min, Point(1, 3));
+        assert_eq!(bounds.max, Point(8, 9));
+        assert_eq!((bounds.width(), bounds.height()), (7, 6));
+        assert!(bounds.contains(Point(8, 9)));
+        assert!(bounds.is_edge(Point(1, 5)));
+        assert_eq!(bounds.points().count(), 8 * 7);
+        assert_eq!(Bounds::<i32>::of(vec![]), None);
+    }
+
+    proptest! {
+        #[test]
+        fn point_round_trips(x: i64, y: i64) {
+            prop_assert_eq!(Point(x, y).to_string().parse(), Ok(Point(x, y)));
+            prop_assert_eq!(format!("  {} ,  {} ", x, y).parse(), Ok(Point(x, y)));
+        }
+
+        #[test]
+        fn point_parse_never_panics(source in "\\PC*") {
+            let _ = source.parse::<Point<u8>>();
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::Rng;
+
+/// A list of `size` frequency changes (at least three) whose frequencies are sure to repeat.
+///
+/// The changes add up to a small drift `d`, and one prefix sum is placed exactly `d` below
+/// the first frequency, so the second pass runs into it again.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut changes = (0..size)
+        .map(|_| match rng.range(-50, 50) {
+            0 => 1,
+            change => change,
+        })
+        .collect::<Vec<i64>>();
+
+    let drift = rng.range(1, 10) * if rng.chance(50) { 1 } else { -1 };
+    let first = changes[0];
+    let repeat = 2 + rng.index(size - 2);
+    changes[repeat - 1] = first - drift - changes[..repeat - 1].iter().sum::<i64>();
+    changes[size - 1] = drift - changes[..size - 1].iter().sum::<i64>();
+
+    changes.iter().map(|c| format!("{:+}\n", c)).collect()
+}
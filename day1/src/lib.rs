@@ -0,0 +1,452 @@
+extern crate aoc_common;
+#[cfg(test)]
+extern crate proptest;
+#[macro_use]
+extern crate serde_json;
+
+use aoc_common::{Output, ParseError, Scanner, Solution};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::io::BufRead;
+
+mod generate;
+
+pub use generate::generate;
+
+fn parse_change(line: &str) -> Result<i32, ParseError> {
+    let mut scanner = Scanner::new(line);
+    let change = scanner.integer("frequency change")?;
+    scanner.end()?;
+
+    Ok(change)
+}
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    aoc_common::parse_lines_with(input, parse_change)
+}
+
+pub fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Vec<i32>> {
+    aoc_common::read_lines_with(reader, parse_change)
+}
+
+pub fn part1(input: &[i32]) -> i32 {
+    input.iter().sum()
+}
+
+/// The first frequency reached twice, and the change that reached it the second time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Repeat {
+    pub frequency: i64,
+    /// The number of whole passes over the changes before the one with the repeat.
+    pub iteration: usize,
+    /// The index of the change, within its pass, that reached the frequency again.
+    pub index: usize,
+}
+
+impl Repeat {
+    /// The repeat of `frequency` after `time` changes in total, from a list of `len` changes.
+    fn at(frequency: i64, time: usize, len: usize) -> Repeat {
+        Repeat {
+            frequency,
+            iteration: (time - 1) / len,
+            index: (time - 1) % len,
+        }
+    }
+}
+
+impl Display for Repeat {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(formatter, "{}", self.frequency)
+    }
+}
+
+impl Output for Repeat {
+    fn data(&self) -> Option<Value> {
+        Some(json!({
+            "frequency": self.frequency,
+            "iteration": self.iteration,
+            "index": self.index,
+        }))
+    }
+}
+
+/// The frequencies the changes go through, cycling through them forever.
+///
+/// Only one pass is stored: after `k` whole passes and `j` more changes the frequency is
+/// `p[j] + k * drift`, where `p` are the prefix sums of the changes and `drift` is their total.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frequencies {
+    sums: Vec<i64>,
+}
+
+impl Frequencies {
+    pub fn new(changes: &[i32]) -> Frequencies {
+        let mut sums = Vec::with_capacity(changes.len() + 1);
+        sums.push(0);
+        for &change in changes {
+            let last = sums[sums.len() - 1];
+            sums.push(last + i64::from(change));
+        }
+
+        Frequencies { sums }
+    }
+
+    /// The number of changes in a pass.
+    pub fn len(&self) -> usize {
+        self.sums.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How much the frequency changes over a whole pass.
+    pub fn drift(&self) -> i64 {
+        self.sums[self.len()]
+    }
+
+    /// The frequency after `changes` changes, which can be any number of passes.
+    pub fn after(&self, changes: usize) -> i64 {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let (passes, rest) = (changes / self.len(), changes % self.len());
+        self.sums[rest] + passes as i64 * self.drift()
+    }
+
+    /// The lowest and highest frequencies reached during the first `passes` passes, including
+    /// the starting frequency of 0.
+    pub fn range(&self, passes: usize) -> (i64, i64) {
+        if passes == 0 {
+            return (0, 0);
+        }
+
+        let min = self.sums.iter().min().unwrap();
+        let max = self.sums.iter().max().unwrap();
+        // Each pass shifts the whole of the first one by the drift, so the last is the furthest.
+        let shift = (passes - 1) as i64 * self.drift();
+        (min + shift.min(0), max + shift.max(0))
+    }
+
+    /// Every frequency reached at least twice during the first `passes` passes, with how many
+    /// times it was reached. The starting frequency of 0 counts as reached.
+    ///
+    /// Frequency `p[j]` is reached again `passes` times over, shifted by the drift each time,
+    /// so with drift each prefix sum covers a run of `passes` consecutive steps of the drift.
+    /// The prefix sums that are congruent modulo the drift share their steps, and sweeping
+    /// over the start and end of each run finds where two or more overlap.
+    pub fn repeated(&self, passes: usize) -> BTreeMap<i64, usize> {
+        let mut repeated = BTreeMap::new();
+        if self.is_empty() || passes == 0 {
+            return repeated;
+        }
+
+        let len = self.len();
+        let drift = self.drift();
+        // The frequency at the very end is where the next pass would start.
+        let runs = (0..len).map(|j| (self.sums[j], if j == 0 { passes + 1 } else { passes }));
+
+        if drift == 0 {
+            for (sum, count) in runs {
+                *repeated.entry(sum).or_insert(0) += count;
+            }
+            repeated.retain(|_, &mut count| count >= 2);
+            return repeated;
+        }
+
+        let mut groups = HashMap::new();
+        for (sum, count) in runs {
+            let residue = sum.rem_euclid(drift);
+            let step = (sum - residue) / drift;
+            let edges = groups.entry(residue).or_insert_with(Vec::new);
+            edges.push((step, 1));
+            edges.push((step + count as i64, -1));
+        }
+
+        for (residue, mut edges) in groups {
+            edges.sort();
+            let mut covered = 0;
+            for pair in edges.windows(2) {
+                let ((step, change), (next, _)) = (pair[0], pair[1]);
+                covered += change;
+                if covered >= 2 {
+                    for step in step..next {
+                        repeated.insert(residue + step * drift, covered as usize);
+                    }
+                }
+            }
+        }
+
+        repeated
+    }
+}
+
+/// Finds the first frequency reached twice, counting the starting frequency of 0, or `None` if
+/// no frequency is ever reached twice.
+///
+/// After `j` changes of pass `k` the frequency is `p[j] + k * drift`, where `p` are the prefix
+/// sums and `drift` is the sum of a whole pass. Two of those can only meet when their prefix
+/// sums are congruent modulo the drift, and then the later one is the smaller in the direction
+/// of the drift, which catches up with the nearest larger one after `(p[i] - p[j]) / drift`
+/// passes. So rather than cycling through the changes, this groups the prefix sums by residue
+/// and sorts each group, which takes O(n log n) time.
+pub fn first_repeat(input: &[i32]) -> Option<Repeat> {
+    let frequencies = Frequencies::new(input);
+    let (len, drift, sums) = (frequencies.len(), frequencies.drift(), &frequencies.sums);
+
+    // Without drift the pass ends where it started, so there is always a repeat within it.
+    let first_pass = if drift == 0 { &sums[..] } else { &sums[..len] };
+    let mut seen = HashSet::new();
+    for (time, &sum) in first_pass.iter().enumerate() {
+        if !seen.insert(sum) {
+            return Some(Repeat::at(sum, time, len));
+        }
+    }
+
+    if drift == 0 {
+        return None;
+    }
+
+    let mut groups = HashMap::new();
+    for (j, &sum) in first_pass.iter().enumerate() {
+        groups
+            .entry(sum.rem_euclid(drift))
+            .or_insert_with(Vec::new)
+            .push((sum, j));
+    }
+
+    let mut first = None;
+    for group in groups.values_mut() {
+        group.sort_by_key(|&(sum, _)| sum * drift.signum());
+        for pair in group.windows(2) {
+            let (sum, j) = pair[0];
+            let (next, _) = pair[1];
+            let time = (next - sum) / drift * len as i64 + j as i64;
+            if first.is_none_or(|(earliest, _)| time < earliest) {
+                first = Some((time, next));
+            }
+        }
+    }
+
+    first.map(|(time, frequency)| Repeat::at(frequency, time as usize, len))
+}
+
+pub fn part2(input: &[i32]) -> aoc_common::Result<Repeat> {
+    first_repeat(input).ok_or_else(|| "no frequency is ever reached twice".into())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = Repeat;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Self::Input> {
+        parse_reader(reader)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+    use proptest::prelude::*;
+
+    fn changes(input: &str) -> Vec<i32> {
+        parse(&input.replace(", ", "\n")).unwrap()
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&changes("+1, -2, +3, +1")), 3);
+        assert_eq!(part1(&changes("+1, +1, +1")), 3);
+        assert_eq!(part1(&changes("+1, +1, -2")), 0);
+        assert_eq!(part1(&changes("-1, -2, -3")), -6);
+    }
+
+    fn repeat(input: &str) -> i64 {
+        part2(&changes(input)).unwrap().frequency
+    }
+
+    /// Cycles through the changes for at most `passes` passes, like the puzzle describes.
+    fn simulate(input: &[i32], passes: usize) -> Option<Repeat> {
+        let mut seen = vec![0].into_iter().collect::<HashSet<i64>>();
+        let mut frequency = 0;
+        for time in 1..=input.len() * passes {
+            frequency += i64::from(input[(time - 1) % input.len()]);
+            if !seen.insert(frequency) {
+                return Some(Repeat::at(frequency, time, input.len()));
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(repeat("+1, -2, +3, +1"), 2);
+        assert_eq!(repeat("+1, -1"), 0);
+        assert_eq!(repeat("+3, +3, +4, -2, -4"), 10);
+        assert_eq!(repeat("-6, +3, +8, +5, -6"), 5);
+        assert_eq!(repeat("+7, +7, -2, -7, -4"), 14);
+    }
+
+    #[test]
+    fn repeats_report_when_they_happen() {
+        let expected = Repeat {
+            frequency: 2,
+            iteration: 1,
+            index: 1,
+        };
+        assert_eq!(first_repeat(&changes("+1, -2, +3, +1")), Some(expected));
+        let expected = Repeat {
+            frequency: 0,
+            iteration: 0,
+            index: 1,
+        };
+        assert_eq!(first_repeat(&changes("+1, -1")), Some(expected));
+    }
+
+    #[test]
+    fn starting_frequency_counts_as_reached() {
+        let expected = Repeat {
+            frequency: 0,
+            iteration: 1,
+            index: 0,
+        };
+        assert_eq!(first_repeat(&changes("-1, +2")), Some(expected));
+    }
+
+    #[test]
+    fn part2_rejects_changes_that_never_repeat() {
+        assert!(part2(&changes("+1, +1")).is_err());
+        assert!(part2(&changes("-2, -2")).is_err());
+        assert!(part2(&[]).is_err());
+    }
+
+    #[test]
+    fn frequencies_after_any_number_of_changes() {
+        let frequencies = Frequencies::new(&changes("+1, -2, +3, +1"));
+        assert_eq!(frequencies.drift(), 3);
+        assert_eq!(frequencies.after(0), 0);
+        assert_eq!(frequencies.after(3), 2);
+        assert_eq!(frequencies.after(4), 3);
+        assert_eq!(frequencies.after(6), 2);
+        assert_eq!(frequencies.after(4_000_001), 3_000_001);
+        assert_eq!(Frequencies::new(&[]).after(10), 0);
+    }
+
+    #[test]
+    fn frequency_range_grows_with_drift() {
+        let frequencies = Frequencies::new(&changes("-6, +3, +8, +5, -6"));
+        assert_eq!(frequencies.range(0), (0, 0));
+        assert_eq!(frequencies.range(1), (-6, 10));
+        assert_eq!(frequencies.range(3), (-6, 18));
+        let frequencies = Frequencies::new(&changes("-1, -2, -3"));
+        assert_eq!(frequencies.range(2), (-12, 0));
+    }
+
+    #[test]
+    fn repeated_frequencies_count_every_visit() {
+        let frequencies = Frequencies::new(&changes("+1, -1"));
+        let expected = vec![(0, 3), (1, 2)].into_iter().collect::<BTreeMap<_, _>>();
+        assert_eq!(frequencies.repeated(2), expected);
+
+        let frequencies = Frequencies::new(&changes("+1, -2, +3, +1"));
+        assert!(frequencies.repeated(1).is_empty());
+        let expected = vec![(2, 2)].into_iter().collect::<BTreeMap<_, _>>();
+        assert_eq!(frequencies.repeated(2), expected);
+    }
+
+    #[test]
+    fn parse_rejects_bad_changes() {
+        assert_eq!(
+            parse("+1\n\n+x\n"),
+            Err(ParseError::new(
+                3,
+                1,
+                "frequency change",
+                Some("+x".to_string())
+            ))
+        );
+        assert_eq!(
+            parse("+1 +2"),
+            Err(ParseError::new(1, 4, "end of line", Some("+2".to_string())))
+        );
+    }
+
+    #[test]
+    fn parse_reader_matches_parse() {
+        let input = "+1\n-2\n+3\n+1\n";
+        assert_eq!(
+            parse_reader(input.as_bytes()).unwrap(),
+            parse(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn generated_changes_repeat() {
+        let input = parse(&generate(&mut Rng::new(1), 50)).unwrap();
+        assert_eq!(input.len(), 50);
+        assert!(part2(&input).is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn changes_round_trip(changes: Vec<i32>) {
+            let input = changes.iter().map(|c| format!("{:+}\n", c)).collect::<String>();
+            prop_assert_eq!(parse(&input), Ok(changes));
+        }
+
+        #[test]
+        fn first_repeat_matches_simulation(input in prop::collection::vec(-10i32..10, 1..8)) {
+            // Changes this small always repeat within a few hundred passes if they ever do.
+            prop_assert_eq!(first_repeat(&input), simulate(&input, 1000));
+        }
+
+        #[test]
+        fn frequencies_match_simulation(
+            input in prop::collection::vec(-10i32..10, 1..8),
+            passes in 0usize..6,
+        ) {
+            let frequencies = Frequencies::new(&input);
+            let mut reached = BTreeMap::new();
+            let mut frequency = 0;
+            for time in 0..=input.len() * passes {
+                if time > 0 {
+                    frequency += i64::from(input[(time - 1) % input.len()]);
+                }
+                prop_assert_eq!(frequencies.after(time), frequency);
+                *reached.entry(frequency).or_insert(0) += 1;
+            }
+
+            let min = *reached.keys().next().unwrap();
+            let max = *reached.keys().next_back().unwrap();
+            prop_assert_eq!(frequencies.range(passes), (min, max));
+            reached.retain(|_, &mut count| count >= 2);
+            prop_assert_eq!(frequencies.repeated(passes), reached);
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = parse(&input);
+        }
+    }
+}
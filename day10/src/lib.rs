@@ -0,0 +1,328 @@
+use aoc_common::{Bounds, Frames, Grid, Output, ParseError, Point, Render, Scanner, Solution};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Particle {
+    position: Point<isize>,
+    velocity: (isize, isize),
+}
+
+impl FromStr for Particle {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(source);
+        scanner.expect("position=<")?;
+        let px = scanner.integer("x position")?;
+        scanner.expect(",")?;
+        let py = scanner.integer("y position")?;
+        scanner.expect(">")?;
+        scanner.expect("velocity=<")?;
+        let vx = scanner.integer("x velocity")?;
+        scanner.expect(",")?;
+        let vy = scanner.integer("y velocity")?;
+        scanner.expect(">")?;
+        scanner.end()?;
+
+        let position = Point(px, py);
+        let velocity = (vx, vy);
+        Ok(Particle { position, velocity })
+    }
+}
+
+impl Display for Particle {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let Point(px, py) = self.position;
+        let (vx, vy) = self.velocity;
+        write!(
+            formatter,
+            "position=<{}, {}> velocity=<{}, {}>",
+            px, py, vx, vy
+        )
+    }
+}
+
+/// The particle positions at the moment they line up into text.
+pub struct Message {
+    positions: HashSet<Point<isize>>,
+}
+
+impl Display for Message {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let bounds = match Bounds::of(self.positions.iter().cloned()) {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        for point @ Point(x, y) in bounds.points() {
+            if x == bounds.min.0 && y != bounds.min.1 {
+                writeln!(formatter)?;
+            }
+
+            match self.positions.get(&point) {
+                Some(_) => write!(formatter, "#")?,
+                None => write!(formatter, ".")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Output for Message {
+    fn data(&self) -> Option<Value> {
+        let message = self.to_string();
+        Some(json!({ "rows": message.lines().collect::<Vec<&str>>() }))
+    }
+}
+
+fn tick(particles: &[Particle]) -> Vec<Particle> {
+    particles
+        .iter()
+        .map(|particle| {
+            let Point(px, py) = particle.position;
+            let (vx, vy) = particle.velocity;
+
+            let position = Point(px + vx, py + vy);
+            Particle {
+                position,
+                velocity: particle.velocity,
+            }
+        })
+        .collect()
+}
+
+fn size(particles: &[Particle]) -> (isize, isize) {
+    Bounds::of(particles.iter().map(|p| p.position))
+        .map(|bounds| (bounds.width(), bounds.height()))
+        .unwrap_or_default()
+}
+
+fn converge(particles: &[Particle]) -> (Message, usize) {
+    let mut particles = particles.to_vec();
+    let (mut width, mut height) = size(&particles);
+    for t in 0.. {
+        let next = tick(&particles);
+        let (next_width, next_height) = size(&next);
+
+        if next_width > width || next_height > height {
+            let positions = particles.iter().map(|p| p.position).collect();
+            return (Message { positions }, t);
+        } else {
+            particles = next;
+            width = next_width;
+            height = next_height;
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Particle>, ParseError> {
+    aoc_common::parse_lines(input)
+}
+
+pub fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Vec<Particle>> {
+    aoc_common::read_lines(reader)
+}
+
+pub fn part1(input: &[Particle]) -> Message {
+    let (message, _) = converge(input);
+    message
+}
+
+pub fn part2(input: &[Particle]) -> usize {
+    let (_, t) = converge(input);
+    t
+}
+
+/// How many ticks before the message appears are drawn when rendering.
+const RENDER_TICKS: usize = 20;
+
+/// Draws the last ticks before the particles line up, framed around the message.
+pub fn render(input: &[Particle], frames: &mut Frames) -> aoc_common::Result<()> {
+    let (message, t) = converge(input);
+    let bounds = match Bounds::of(message.positions.iter().cloned()) {
+        Some(bounds) => bounds,
+        None => return Ok(()),
+    };
+
+    let (margin_x, margin_y) = (bounds.width() / 2 + 1, bounds.height() / 2 + 1);
+    let (left, top) = (bounds.min.0 - margin_x, bounds.min.1 - margin_y);
+    let width = (bounds.width() + 2 * margin_x + 1) as usize;
+    let height = (bounds.height() + 2 * margin_y + 1) as usize;
+
+    for tick in t.saturating_sub(RENDER_TICKS)..=t {
+        let mut grid = Grid::new(width, height, false);
+        let n = tick as isize;
+        for particle in input {
+            let Point(px, py) = particle.position;
+            let (vx, vy) = particle.velocity;
+            let (x, y) = (px + vx * n - left, py + vy * n - top);
+            if (0..width as isize).contains(&x) && (0..height as isize).contains(&y) {
+                grid[Point(x as usize, y as usize)] = true;
+            }
+        }
+
+        frames.write(&grid)?;
+    }
+
+    Ok(())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Particle>;
+    type Part1 = Message;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Self::Input> {
+        parse_reader(reader)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+impl Render for Day10 {
+    fn render(input: &Self::Input, frames: &mut Frames) -> aoc_common::Result<()> {
+        render(input, frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>
+";
+
+    #[test]
+    fn part1_example() {
+        let message = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###";
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()).to_string(), message);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn render_draws_every_tick_up_to_the_message() {
+        let dir = std::env::temp_dir().join(format!("day10-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir, 1, 100);
+        render(&parse(EXAMPLE).unwrap(), &mut frames).unwrap();
+
+        assert_eq!(frames.written(), 4);
+        assert!(dir.join("frame-0003.pbm").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parse_particle() {
+        let particle = "position=<-6, 10> velocity=< 2, -2>"
+            .parse::<Particle>()
+            .unwrap();
+        assert_eq!(particle.position, Point(-6, 10));
+        assert_eq!(particle.velocity, (2, -2));
+    }
+
+    #[test]
+    fn parse_rejects_bad_particles() {
+        assert_eq!(
+            parse("position=< 9,  1> velocity=< 0,  2>\nposition=< 7,  0> speed=<-1,  0>\n").err(),
+            Some(ParseError::new(
+                2,
+                19,
+                "`velocity=<`",
+                Some("speed=<-1,".to_string())
+            ))
+        );
+    }
+
+    fn particle() -> impl Strategy<Value = Particle> {
+        any::<(isize, isize, isize, isize)>().prop_map(|(px, py, vx, vy)| Particle {
+            position: Point(px, py),
+            velocity: (vx, vy),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn particle_round_trips(particle in particle()) {
+            prop_assert_eq!(particle.to_string().parse(), Ok(particle));
+        }
+
+        #[test]
+        fn padded_particles_parse(px in -99isize..100, py in -99isize..100, vx in -9isize..10, vy in -9isize..10) {
+            let source = format!("position=<{:>3}, {:>3}> velocity=<{:>2}, {:>2}>", px, py, vx, vy);
+            let expected = Particle {
+                position: Point(px, py),
+                velocity: (vx, vy),
+            };
+            prop_assert_eq!(source.parse(), Ok(expected));
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use aoc_common::{Output, ParseError, Point, Scanner, Solution};
+use itertools::iproduct;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+/// The width and height of the fuel cell grid in the puzzle.
+pub const GRID_SIZE: isize = 300;
+
+/// The size of the squares considered by part 1.
+pub const SQUARE_SIZE: isize = 3;
+
+/// The top-left corner and size of a square of fuel cells.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Square {
+    position: Point<isize>,
+    size: isize,
+}
+
+impl Display for Square {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(formatter, "{},{}", self.position, self.size)
+    }
+}
+
+impl Output for Square {
+    fn data(&self) -> Option<Value> {
+        let Point(x, y) = self.position;
+        Some(json!({ "x": x, "y": y, "size": self.size }))
+    }
+}
+
+/// Parses the grid serial number.
+pub fn parse(input: &str) -> Result<isize, ParseError> {
+    let mut scanner = Scanner::new(input.trim());
+    let serial = scanner.integer("grid serial number")?;
+    scanner.end()?;
+
+    Ok(serial)
+}
+
+fn power_level(serial: isize, Point(x, y): Point<isize>) -> isize {
+    let rack_id = x + 10;
+    let power_level = rack_id * y;
+    let power_level = power_level + serial;
+    let power_level = power_level * rack_id;
+    let hundreds = power_level
+        .to_string()
+        .chars()
+        .rev()
+        .nth(2)
+        .map(|c| c as u8 - 48)
+        .unwrap_or_default() as isize;
+    hundreds - 5
+}
+
+/// The power level of every cell in a `size` by `size` grid, indexed from 1.
+pub fn grid(serial: isize, size: isize) -> HashMap<Point<isize>, isize> {
+    iproduct!(1..=size, 1..=size)
+        .map(|(x, y)| {
+            let point = Point(x, y);
+            let power_level = power_level(serial, point);
+            (point, power_level)
+        })
+        .collect()
+}
+
+/// The top-left corner of the `square_size` square with the most power.
+pub fn part1(grid: &HashMap<Point<isize>, isize>, square_size: isize) -> Point<isize> {
+    let (point, _) = grid
+        .keys()
+        .filter_map(|&start| {
+            let Point(x, y) = start;
+            let score: isize = iproduct!(0..square_size, 0..square_size)
+                .map(|(dx, dy)| Point(x + dx, y + dy))
+                .map(|point| grid.get(&point).cloned())
+                .collect::<Option<Vec<isize>>>()?
+                .iter()
+                .sum();
+            Some((start, score))
+        })
+        .max_by_key(|&(_, score)| score)
+        .unwrap();
+
+    point
+}
+
+/// The square of any size with the most power in a `size` by `size` grid.
+pub fn part2(grid: &HashMap<Point<isize>, isize>, size: isize) -> Square {
+    let cache = iproduct!(1..=size, 1..=size)
+        .map(|(x, y)| Point(x, y))
+        .fold(HashMap::new(), |mut acc, point| {
+            let Point(x, y) = point;
+            let prev = acc.get(&Point(x, y - 1)).cloned().unwrap_or_default();
+            let row: isize = (1..=x).map(|i| grid[&Point(i, y)]).sum();
+
+            let score: isize = prev + row;
+
+            acc.insert(point, score);
+            acc
+        });
+
+    // The best square at each corner, keeping the last of any ties as a single scan would.
+    let corners = iproduct!(1..=size, 1..=size)
+        .map(|(x, y)| Point(x, y))
+        .collect::<Vec<Point<isize>>>();
+    let best = aoc_common::par_map(corners, |position| {
+        let Point(x, y) = position;
+        let end = size - x.max(y);
+        (0..=end)
+            .map(|i| {
+                let target = cache[&Point(x + i, y + i)];
+                let top = cache.get(&Point(x + i, y - 1)).cloned().unwrap_or_default();
+                let side = cache.get(&Point(x - 1, y + i)).cloned().unwrap_or_default();
+                let overage = cache.get(&Point(x - 1, y - 1)).cloned().unwrap_or_default();
+
+                (position, i, target - top - side + overage)
+            })
+            .max_by_key(|&(_, _, power)| power)
+            .unwrap()
+    });
+
+    let (position, offset, _) = best.into_iter().max_by_key(|&(_, _, power)| power).unwrap();
+
+    Square {
+        position,
+        size: offset + 1,
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = HashMap<Point<isize>, isize>;
+    type Part1 = Point<isize>;
+    type Part2 = Square;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(grid(parse(input)?, GRID_SIZE))
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input, SQUARE_SIZE))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input, GRID_SIZE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_level_examples() {
+        assert_eq!(power_level(8, Point(3, 5)), 4);
+        assert_eq!(power_level(57, Point(122, 79)), -5);
+        assert_eq!(power_level(39, Point(217, 196)), 0);
+        assert_eq!(power_level(71, Point(101, 153)), 4);
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&grid(18, GRID_SIZE), SQUARE_SIZE), Point(33, 45));
+        assert_eq!(part1(&grid(42, GRID_SIZE), SQUARE_SIZE), Point(21, 61));
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            part2(&grid(18, GRID_SIZE), GRID_SIZE).to_string(),
+            "90,269,16"
+        );
+        assert_eq!(
+            part2(&grid(42, GRID_SIZE), GRID_SIZE).to_string(),
+            "232,251,12"
+        );
+    }
+
+    #[test]
+    fn parse_serial_number() {
+        assert_eq!(parse("5535\n"), Ok(5535));
+        assert_eq!(
+            parse("serial"),
+            Err(ParseError::new(
+                1,
+                1,
+                "grid serial number",
+                Some("serial".to_string())
+            ))
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use aoc_common::{Frames, Grid, ParseError, Point, Render, Scanner, Solution};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+
+pub struct Pots {
+    rules: HashMap<String, char>,
+    initial_state: HashSet<isize>,
+}
+
+fn calculate(rules: &HashMap<String, char>, state: &HashSet<isize>) -> HashSet<isize> {
+    let start = state.iter().min().unwrap() - 1;
+    let end = state.iter().max().unwrap() + 1;
+    (start..=end)
+        .filter(|i| {
+            let lookup = (i - 2..=i + 2)
+                .map(|j| state.get(&j).cloned().map(|_| '#').unwrap_or('.'))
+                .collect::<String>();
+            match rules.get(&lookup) {
+                Some(&next) => next == '#',
+                None => false,
+            }
+        })
+        .collect()
+}
+
+fn parse_pots<'a>(scanner: &mut Scanner<'a>, what: &str) -> Result<&'a str, ParseError> {
+    let column = scanner.column();
+    let pots = scanner.word(what)?;
+    match pots.chars().position(|c| c != '#' && c != '.') {
+        Some(i) => {
+            let found = pots.chars().nth(i).map(String::from);
+            Err(ParseError::new(1, column + i, "`#` or `.`", found))
+        }
+        None => Ok(pots),
+    }
+}
+
+fn parse_rule(line: &str) -> Result<(String, char), ParseError> {
+    let mut scanner = Scanner::new(line);
+    let column = scanner.column();
+    let matches = parse_pots(&mut scanner, "five pots")?;
+    if matches.len() != 5 {
+        let found = Some(matches.to_string());
+        return Err(ParseError::new(1, column, "five pots", found));
+    }
+    scanner.expect("=>")?;
+    let next = parse_pots(&mut scanner, "`#` or `.`")?;
+    if next.len() != 1 {
+        return Err(ParseError::new(1, column, "`#` or `.`", Some(next.into())));
+    }
+    scanner.end()?;
+
+    Ok((matches.into(), next.chars().next().unwrap_or('.')))
+}
+
+fn parse_initial_state(line: &str) -> Result<HashSet<isize>, ParseError> {
+    let mut scanner = Scanner::new(line);
+    scanner.expect("initial state:")?;
+    let initial_state = parse_pots(&mut scanner, "pots")?
+        .chars()
+        .enumerate()
+        .filter_map(|(i, c)| if c == '#' { Some(i as isize) } else { None })
+        .collect();
+    scanner.end()?;
+
+    Ok(initial_state)
+}
+
+pub fn parse(input: &str) -> Result<Pots, ParseError> {
+    let mut lines = input.lines();
+    let initial_state = parse_initial_state(lines.next().unwrap_or_default())?;
+    let rules = lines
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_rule(line).map_err(|e| e.on_line(i + 2)))
+        .collect::<Result<HashMap<String, char>, ParseError>>()?;
+
+    Ok(Pots {
+        rules,
+        initial_state,
+    })
+}
+
+pub fn part1(
+    Pots {
+        rules,
+        initial_state,
+    }: &Pots,
+) -> isize {
+    let state = (0..20).fold(initial_state.clone(), |last_state, _| {
+        calculate(rules, &last_state)
+    });
+
+    state.iter().sum::<isize>()
+}
+
+pub fn part2(
+    Pots {
+        rules,
+        initial_state,
+    }: &Pots,
+) -> isize {
+    let (generation, growth, sum, _) = (1..)
+        .try_fold(
+            (0, 0, 0, initial_state.clone()),
+            |(_, last_growth, last_sum, last_state), g| {
+                let state = calculate(rules, &last_state);
+                let sum = state.iter().sum::<isize>();
+                let growth = sum - last_sum;
+                debug!(generation = g, sum, growth, "generation");
+                if last_growth == growth {
+                    Err((g, growth, sum, state))
+                } else {
+                    Ok((g, growth, sum, state))
+                }
+            },
+        )
+        .unwrap_err();
+    debug!(generation, growth, "growth stabilised");
+
+    let rest = (50_000_000_000isize - generation) * growth;
+    sum + rest
+}
+
+/// How many generations are drawn when rendering.
+const RENDER_GENERATIONS: usize = 100;
+
+/// Draws the first generations as a single image, one row of pots per generation.
+pub fn render(
+    Pots {
+        rules,
+        initial_state,
+    }: &Pots,
+    frames: &mut Frames,
+) -> aoc_common::Result<()> {
+    let states = std::iter::successors(Some(initial_state.clone()), |state| {
+        if state.is_empty() {
+            None
+        } else {
+            Some(calculate(rules, state))
+        }
+    })
+    .take(RENDER_GENERATIONS + 1)
+    .collect::<Vec<HashSet<isize>>>();
+
+    let pots = states.iter().flatten();
+    let (left, right) = match (pots.clone().min(), pots.max()) {
+        (Some(&left), Some(&right)) => (left, right),
+        _ => return Ok(()),
+    };
+
+    let mut grid = Grid::new((right - left + 1) as usize, states.len(), false);
+    for (y, state) in states.iter().enumerate() {
+        for pot in state {
+            grid[Point((pot - left) as usize, y)] = true;
+        }
+    }
+
+    frames.write(&grid)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Pots;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+impl Render for Day12 {
+    fn render(input: &Self::Input, frames: &mut Frames) -> aoc_common::Result<()> {
+        render(input, frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 325);
+    }
+
+    #[test]
+    fn parse_rejects_bad_rules() {
+        assert_eq!(
+            parse("initial state: #..#\n\n..#.x => #\n").err(),
+            Some(ParseError::new(3, 5, "`#` or `.`", Some("x".to_string())))
+        );
+        assert_eq!(
+            parse("initial state: #..#\n\n..#. => #\n").err(),
+            Some(ParseError::new(3, 1, "five pots", Some("..#.".to_string())))
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{last_cart, parse};
+use aoc_common::Rng;
+use std::collections::HashSet;
+
+fn draw_tracks(loops: usize) -> Vec<Vec<char>> {
+    let (width, height) = (6 * loops + 3, 3 * loops + 6);
+    let mut map = vec![vec![' '; width]; height];
+    for i in 0..loops {
+        let (left, top) = (6 * i, 3 * i);
+        let (right, bottom) = (left + 8, top + 8);
+        let mut draw = |x: usize, y: usize, track: char, crossing: char| {
+            map[y][x] = if map[y][x] == crossing { '+' } else { track };
+        };
+        for x in left..=right {
+            draw(x, top, '-', '|');
+            draw(x, bottom, '-', '|');
+        }
+        for y in top..=bottom {
+            draw(left, y, '|', '-');
+            draw(right, y, '|', '-');
+        }
+        map[top][left] = '/';
+        map[top][right] = '\\';
+        map[bottom][left] = '\\';
+        map[bottom][right] = '/';
+    }
+
+    map
+}
+
+fn place_carts(rng: &mut Rng, tracks: &[Vec<char>], carts: usize) -> String {
+    let mut map = tracks.to_vec();
+    let mut placed = 0;
+    while placed < carts {
+        let y = rng.index(map.len());
+        let x = rng.index(map[y].len());
+        let cart = match map[y][x] {
+            '-' => *rng.choose(&['<', '>']),
+            '|' => *rng.choose(&['^', 'v']),
+            _ => continue,
+        };
+        map[y][x] = cart;
+        placed += 1;
+    }
+
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// Carts in head-on pairs on straight track, which crash into each other on the first tick,
+/// and one more cart too far from any pair to be caught up in their crashes.
+fn place_pairs(rng: &mut Rng, tracks: &[Vec<char>], carts: usize) -> String {
+    let mut map = tracks.to_vec();
+    let mut pairs = vec![];
+    for (y, row) in map.iter().enumerate() {
+        for (x, &track) in row.iter().enumerate() {
+            if track == '-' && row.get(x + 1) == Some(&'-') {
+                pairs.push([(x, y, '>'), (x + 1, y, '<')]);
+            }
+            if track == '|' && map.get(y + 1).map(|row| row[x]) == Some('|') {
+                pairs.push([(x, y, 'v'), (x, y + 1, '^')]);
+            }
+        }
+    }
+    rng.shuffle(&mut pairs);
+
+    let mut taken = HashSet::new();
+    let pairs = pairs
+        .into_iter()
+        .filter(|pair| {
+            let free = pair.iter().all(|&(x, y, _)| !taken.contains(&(x, y)));
+            if free {
+                taken.extend(pair.iter().map(|&(x, y, _)| (x, y)));
+            }
+            free
+        })
+        .take(carts / 2)
+        .collect::<Vec<_>>();
+    assert_eq!(pairs.len(), carts / 2, "Room for every pair of carts");
+
+    let lone = (0..map.len())
+        .flat_map(|y| (0..map[y].len()).map(move |x| (x, y)))
+        .filter(|&(x, y)| map[y][x] == '-' || map[y][x] == '|')
+        .filter(|&(x, y)| {
+            taken.iter().all(|&(tx, ty)| {
+                (x as isize - tx as isize).abs() > 1 || (y as isize - ty as isize).abs() > 1
+            })
+        })
+        .collect::<Vec<_>>();
+    let (x, y) = *rng.choose(&lone);
+    map[y][x] = if map[y][x] == '-' {
+        *rng.choose(&['<', '>'])
+    } else {
+        *rng.choose(&['^', 'v'])
+    };
+    for &(x, y, cart) in pairs.iter().flatten() {
+        map[y][x] = cart;
+    }
+
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// How many random placements to try before falling back to `place_pairs`.
+const ATTEMPTS: usize = 10;
+
+/// A diagonal chain of `size` 8x8 loops (at least one), each crossing the next at two
+/// intersections, with an odd number of carts.
+///
+/// Carts are placed at random until the crashes leave a single cart within a generous number
+/// of ticks, as not every placement does. After `ATTEMPTS` tries, which larger chains tend to
+/// need, they are placed in pairs that are sure to leave one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let loops = size.max(1);
+    let tracks = draw_tracks(loops);
+    let carts = ((loops / 2) * 2 + 1).max(3);
+    let ticks = 1000 + 100 * loops;
+
+    for _ in 0..ATTEMPTS {
+        let map = place_carts(rng, &tracks, carts);
+        let parsed = parse(&map).expect("Generated tracks to parse");
+        if last_cart(&parsed, ticks).is_some() {
+            return map;
+        }
+    }
+
+    place_pairs(rng, &tracks, carts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_always_leave_one_cart() {
+        for seed in 0..20 {
+            for &loops in &[1, 2, 50] {
+                let carts = ((loops / 2) * 2 + 1).max(3);
+                let map = place_pairs(&mut Rng::new(seed), &draw_tracks(loops), carts);
+                let tracks = parse(&map).unwrap();
+                assert_eq!(tracks.carts.len(), carts);
+                assert!(last_cart(&tracks, 2).is_some());
+            }
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use aoc_common::{Frames, Grid, ParseError, Point, Render, Rgb, Solution};
+use tracing::{debug, debug_span, trace};
+
+mod generate;
+
+pub use crate::generate::generate;
+
+#[derive(Copy, Clone)]
+enum Track {
+    Straight,
+    CurveLeft,
+    CurveRight,
+    Intersection,
+}
+
+#[derive(Copy, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    fn turn(self, turn: Turn) -> Direction {
+        match (self, turn) {
+            (Direction::Up, Turn::Left) => Direction::Left,
+            (Direction::Up, Turn::Right) => Direction::Right,
+            (Direction::Up, Turn::Straight) => self,
+            (Direction::Down, Turn::Left) => Direction::Right,
+            (Direction::Down, Turn::Right) => Direction::Left,
+            (Direction::Down, Turn::Straight) => self,
+            (Direction::Left, Turn::Left) => Direction::Down,
+            (Direction::Left, Turn::Right) => Direction::Up,
+            (Direction::Left, Turn::Straight) => self,
+            (Direction::Right, Turn::Left) => Direction::Up,
+            (Direction::Right, Turn::Right) => Direction::Down,
+            (Direction::Right, Turn::Straight) => self,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Turn {
+    Left,
+    Right,
+    Straight,
+}
+
+impl Turn {
+    fn next(self) -> Turn {
+        match self {
+            Turn::Left => Turn::Straight,
+            Turn::Straight => Turn::Right,
+            Turn::Right => Turn::Left,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Cart {
+    position: Point<usize>,
+    direction: Direction,
+    next_turn: Turn,
+    crashed: bool,
+}
+
+impl Cart {
+    fn next_position(self) -> Point<usize> {
+        let (dx, dy) = self.direction.offset();
+        self.position
+            .offset(dx, dy)
+            .expect("Cart to stay on the map")
+    }
+
+    fn next(self, track: Track) -> Cart {
+        match track {
+            Track::Straight => self,
+            Track::CurveLeft => {
+                let Cart { direction, .. } = self;
+                match direction {
+                    Direction::Up => Cart {
+                        direction: Direction::Left,
+                        ..self
+                    },
+                    Direction::Down => Cart {
+                        direction: Direction::Right,
+                        ..self
+                    },
+                    Direction::Right => Cart {
+                        direction: Direction::Down,
+                        ..self
+                    },
+                    Direction::Left => Cart {
+                        direction: Direction::Up,
+                        ..self
+                    },
+                }
+            }
+            Track::CurveRight => {
+                let Cart { direction, .. } = self;
+                match direction {
+                    Direction::Up => Cart {
+                        direction: Direction::Right,
+                        ..self
+                    },
+                    Direction::Down => Cart {
+                        direction: Direction::Left,
+                        ..self
+                    },
+                    Direction::Right => Cart {
+                        direction: Direction::Up,
+                        ..self
+                    },
+                    Direction::Left => Cart {
+                        direction: Direction::Down,
+                        ..self
+                    },
+                }
+            }
+            Track::Intersection => {
+                let Cart {
+                    direction,
+                    next_turn,
+                    ..
+                } = self;
+                Cart {
+                    direction: direction.turn(next_turn),
+                    next_turn: next_turn.next(),
+                    ..self
+                }
+            }
+        }
+    }
+}
+
+pub struct Tracks {
+    grid: Grid<Option<Track>>,
+    carts: Vec<Cart>,
+}
+
+pub fn parse(input: &str) -> Result<Tracks, ParseError> {
+    let mut carts = vec![];
+    let grid = Grid::parse(input, |point, ch| {
+        let direction = match ch {
+            '|' | '-' => return Ok(Some(Track::Straight)),
+            '/' => return Ok(Some(Track::CurveRight)),
+            '\\' => return Ok(Some(Track::CurveLeft)),
+            '+' => return Ok(Some(Track::Intersection)),
+            ' ' => return Ok(None),
+            '^' => Direction::Up,
+            '>' => Direction::Right,
+            'v' => Direction::Down,
+            '<' => Direction::Left,
+            _ => {
+                let Point(x, y) = point;
+                let found = Some(ch.to_string());
+                return Err(ParseError::new(y + 1, x + 1, "track or cart", found));
+            }
+        };
+
+        carts.push(Cart {
+            position: point,
+            direction,
+            next_turn: Turn::Left,
+            crashed: false,
+        });
+        Ok(Some(Track::Straight))
+    })?;
+
+    Ok(Tracks { grid, carts })
+}
+
+pub fn part1(Tracks { grid, carts }: &Tracks) -> Point<usize> {
+    let mut carts = carts.to_vec();
+
+    loop {
+        carts.sort_by_key(|&Cart { position, .. }| position);
+
+        for i in 0..carts.len() {
+            let cart = carts[i];
+            let track = grid[cart.position].expect("Cart to be on a track");
+            let next_cart = cart.next(track);
+            let next_point = next_cart.next_position();
+            let crashed = carts
+                .iter()
+                .any(|&Cart { position, .. }| next_point == position);
+
+            if crashed {
+                return next_point;
+            } else {
+                carts[i] = next_cart;
+                carts[i].position = next_point;
+            }
+        }
+    }
+}
+
+/// Moves every cart one step in reading order, removing carts that crash, and returns where
+/// the crashes happened.
+fn tick(grid: &Grid<Option<Track>>, carts: &mut Vec<Cart>) -> Vec<Point<usize>> {
+    let mut crashes = vec![];
+    carts.sort_by_key(|&Cart { position, .. }| position);
+
+    for i in 0..carts.len() {
+        let cart = carts[i];
+        let track = grid[cart.position].expect("Cart to be on a track");
+        let next_cart = cart.next(track);
+        let next_point = next_cart.next_position();
+        let crashed = carts
+            .iter()
+            .position(|&Cart { position, .. }| next_point == position);
+
+        if let Some(other) = crashed {
+            carts[i].crashed = true;
+            carts[other].crashed = true;
+            crashes.push(next_point);
+        } else {
+            carts[i] = next_cart;
+            carts[i].position = next_point;
+        }
+    }
+
+    carts.retain(|Cart { crashed, .. }| !crashed);
+    crashes
+}
+
+/// Runs the carts until only one is left and returns where it is, or `None` if that takes
+/// more than `ticks` ticks.
+fn last_cart(Tracks { grid, carts }: &Tracks, ticks: usize) -> Option<Point<usize>> {
+    let _span = debug_span!("last_cart", carts = carts.len()).entered();
+    let mut carts = carts.to_vec();
+
+    for t in 0..ticks {
+        for crash in tick(grid, &mut carts) {
+            debug!(tick = t, %crash, carts = carts.len(), "crash");
+        }
+        trace!(tick = t, carts = carts.len(), "tick");
+
+        if carts.len() == 1 {
+            let Cart { position, .. } = carts.first().unwrap();
+            return Some(*position);
+        }
+    }
+
+    None
+}
+
+pub fn part2(tracks: &Tracks) -> Point<usize> {
+    last_cart(tracks, usize::MAX).expect("A single cart to be left")
+}
+
+const BACKGROUND: Rgb = [0, 0, 0];
+const TRACK: Rgb = [96, 96, 96];
+const INTERSECTION: Rgb = [160, 160, 160];
+const CART: Rgb = [255, 220, 0];
+const CRASH: Rgb = [255, 0, 0];
+
+fn draw(grid: &Grid<Option<Track>>, carts: &[Cart], crashes: &[Point<usize>]) -> Grid<Rgb> {
+    let mut image = Grid::new(grid.width(), grid.height(), BACKGROUND);
+    for (point, track) in grid.iter() {
+        image[point] = match track {
+            Some(Track::Intersection) => INTERSECTION,
+            Some(_) => TRACK,
+            None => BACKGROUND,
+        };
+    }
+    for &crash in crashes {
+        image[crash] = CRASH;
+    }
+    for cart in carts {
+        image[cart.position] = CART;
+    }
+
+    image
+}
+
+/// Draws the carts after every tick until one is left, marking where crashes happened.
+pub fn render(Tracks { grid, carts }: &Tracks, frames: &mut Frames) -> aoc_common::Result<()> {
+    let mut carts = carts.to_vec();
+    let mut crashes = vec![];
+    frames.write(&draw(grid, &carts, &crashes))?;
+
+    while carts.len() > 1 && !frames.is_full() {
+        crashes.extend(tick(grid, &mut carts));
+        frames.write(&draw(grid, &carts, &crashes))?;
+    }
+
+    Ok(())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Tracks;
+    type Part1 = Point<usize>;
+    type Part2 = Point<usize>;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+impl Render for Day13 {
+    fn render(input: &Self::Input, frames: &mut Frames) -> aoc_common::Result<()> {
+        render(input, frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+
+    const CRASH: &str = r"/->-\        
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/   
+";
+
+    const LAST_CART: &str = r"/>-<\  
+|   |  
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(CRASH).unwrap()), Point(7, 3));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(LAST_CART).unwrap()), Point(6, 4));
+    }
+
+    #[test]
+    fn parse_finds_carts() {
+        let tracks = parse(CRASH).unwrap();
+        let carts = tracks.carts.iter().map(|c| c.position).collect::<Vec<_>>();
+        assert_eq!(carts, vec![Point(2, 0), Point(9, 3)]);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_tracks() {
+        assert_eq!(
+            parse("/->-\\\n| x |\n\\---/\n").err(),
+            Some(ParseError::new(
+                2,
+                3,
+                "track or cart",
+                Some("x".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn generated_tracks_leave_one_cart() {
+        let tracks = parse(&generate(&mut Rng::new(1), 5)).unwrap();
+        assert_eq!(tracks.carts.len(), 5);
+        part2(&tracks);
+    }
+
+    #[test]
+    fn generated_chains_of_any_size_leave_one_cart() {
+        for &size in &[1, 50, 200] {
+            let tracks = parse(&generate(&mut Rng::new(1), size)).unwrap();
+            assert_eq!(tracks.carts.len(), ((size / 2) * 2 + 1).max(3));
+            assert!(last_cart(&tracks, 1000 + 100 * size).is_some());
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use aoc_common::{ParseError, Scanner, Solution};
+use std::collections::VecDeque;
+
+/// The scores of the two recipes on the scoreboard at the start of the puzzle.
+pub const SCOREBOARD: [char; 2] = ['3', '7'];
+
+/// How many scores part 1 reports after the skipped recipes.
+pub const WINDOW: usize = 10;
+
+struct Scoreboard {
+    position: usize,
+    scores: Vec<char>,
+    workers: (usize, usize),
+}
+
+impl Scoreboard {
+    /// A scoreboard starting with `scores`, which must be at least two digits.
+    fn new(scores: &[char]) -> Scoreboard {
+        Scoreboard {
+            position: 0,
+            scores: scores.to_vec(),
+            workers: (0, 1),
+        }
+    }
+}
+
+impl Iterator for Scoreboard {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.scores.len() {
+            let (left, right) = self.workers;
+            let left_score = self.scores[left].to_digit(10).unwrap() as usize;
+            let right_score = self.scores[right].to_digit(10).unwrap() as usize;
+            let score = left_score + right_score;
+            self.scores.extend(score.to_string().chars());
+
+            let left = (left + left_score + 1) % self.scores.len();
+            let right = (right + right_score + 1) % self.scores.len();
+
+            self.workers = (left, right);
+        }
+
+        let item = self.scores.get(self.position).cloned();
+        self.position += 1;
+
+        item
+    }
+}
+
+/// Parses the puzzle input, a run of digits.
+///
+/// The digits are kept as written since part 2 looks for them on the scoreboard, leading
+/// zeros included.
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    let mut scanner = Scanner::new(input.trim());
+    let column = scanner.column();
+    let digits = scanner.word("recipe count")?;
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParseError::new(
+            1,
+            column,
+            "recipe count",
+            Some(digits.to_string()),
+        ));
+    }
+    scanner.end()?;
+
+    Ok(digits.to_string())
+}
+
+/// The `window` scores after the first `recipes` recipes.
+pub fn part1(recipes: usize, scoreboard: &[char], window: usize) -> String {
+    let scoreboard = Scoreboard::new(scoreboard);
+    scoreboard.skip(recipes).take(window).collect()
+}
+
+/// How many recipes appear on the scoreboard before `digits` first does.
+pub fn part2(digits: &str, scoreboard: &[char]) -> usize {
+    let scoreboard = Scoreboard::new(scoreboard);
+    let input_chars = digits.chars().collect::<Vec<char>>();
+    let (answer, _) = scoreboard
+        .enumerate()
+        .try_fold((0, VecDeque::new()), |(_, mut acc), (i, score)| {
+            acc.push_back(score);
+            if acc.len() > input_chars.len() {
+                let _ = acc.pop_front();
+            }
+
+            if acc == input_chars {
+                Err((i - input_chars.len() + 1, acc))
+            } else {
+                Ok((i, acc))
+            }
+        })
+        .unwrap_err();
+
+    answer
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = String;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input.parse()?, &SCOREBOARD, WINDOW))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input, &SCOREBOARD))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(9, &SCOREBOARD, WINDOW), "5158916779");
+        assert_eq!(part1(5, &SCOREBOARD, WINDOW), "0124515891");
+        assert_eq!(part1(18, &SCOREBOARD, WINDOW), "9251071085");
+        assert_eq!(part1(2018, &SCOREBOARD, WINDOW), "5941429882");
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(part2("51589", &SCOREBOARD), 9);
+        assert_eq!(part2("01245", &SCOREBOARD), 5);
+        assert_eq!(part2("92510", &SCOREBOARD), 18);
+        assert_eq!(part2("59414", &SCOREBOARD), 2018);
+    }
+
+    #[test]
+    fn parse_keeps_leading_zeros() {
+        assert_eq!(parse("01245\n"), Ok("01245".to_string()));
+        assert_eq!(
+            parse("12a45"),
+            Err(ParseError::new(
+                1,
+                1,
+                "recipe count",
+                Some("12a45".to_string())
+            ))
+        );
+    }
+}
@@ -1,76 +1,65 @@
-use std::collections::VecDeque;
+use aoc_common::{Answer, Options, Report, Result, Solution, Source};
+use std::env;
+use std::process;
 
-const INPUT: usize = 110_201;
+const USAGE: &str = "day14 [FILE|-] [--recipes DIGITS] [--scoreboard DIGITS] [--window N]";
 
-struct Scoreboard {
-    position: usize,
-    scores: Vec<char>,
-    workers: (usize, usize),
+fn value(args: &mut dyn Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| format!("{} requires a value", flag).into())
 }
 
-impl Default for Scoreboard {
-    fn default() -> Self {
-        Scoreboard {
-            position: 0,
-            scores: vec!['3', '7'],
-            workers: (0, 1),
-        }
-    }
-}
-
-impl Iterator for Scoreboard {
-    type Item = char;
+fn run() -> Result<()> {
+    let mut recipes = None;
+    let mut scoreboard = day14::SCOREBOARD.to_vec();
+    let mut window = day14::WINDOW;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.position >= self.scores.len() {
-            let (left, right) = self.workers;
-            let left_score = self.scores[left].to_digit(10).unwrap() as usize;
-            let right_score = self.scores[right].to_digit(10).unwrap() as usize;
-            let score = left_score + right_score;
-            self.scores.extend(score.to_string().chars());
-
-            let left = (left + left_score + 1) % self.scores.len();
-            let right = (right + right_score + 1) % self.scores.len();
-
-            self.workers = (left, right);
+    let options = Options::parse(env::args().skip(1), USAGE, |flag, args| {
+        match flag {
+            "--recipes" => recipes = Some(value(args, flag)?),
+            "--scoreboard" => scoreboard = value(args, flag)?.chars().collect(),
+            "--window" => {
+                window = value(args, flag)?
+                    .parse()
+                    .map_err(|_| "--window requires a number")?
+            }
+            _ => return Ok(false),
         }
+        Ok(true)
+    })?;
 
-        let item = self.scores.get(self.position).cloned();
-        self.position += 1;
-
-        item
+    if scoreboard.len() < 2 || !scoreboard.iter().all(|c| c.is_ascii_digit()) {
+        return Err("--scoreboard must be at least two digits".into());
     }
-}
 
-fn part1() {
-    let scoreboard = Scoreboard::default();
-    let answer = scoreboard.skip(INPUT).take(10).collect::<String>();
-    println!("part 1: {}", answer);
-}
+    let input = match (recipes, options.file.clone()) {
+        (Some(_), Some(_)) => return Err("give either FILE or --recipes, not both".into()),
+        (Some(recipes), None) => recipes,
+        (None, file) => Source::from_arg(file).read_to_string()?,
+    };
 
-fn part2() {
-    let scoreboard = Scoreboard::default();
-    let input_chars = INPUT.to_string().chars().collect::<Vec<char>>();
-    let (answer, _) = scoreboard
-        .enumerate()
-        .try_fold((0, VecDeque::new()), |(_, mut acc), (i, score)| {
-            acc.push_back(score);
-            if acc.len() > input_chars.len() {
-                let _ = acc.pop_front();
-            }
+    let (recipes, parse_elapsed) = aoc_common::timed(|| day14::parse(&input));
+    let recipes = recipes?;
 
-            if acc == input_chars {
-                Err((i - input_chars.len() + 1, acc))
-            } else {
-                Ok((i, acc))
-            }
-        })
-        .unwrap_err();
+    let count = recipes.parse()?;
+    let (part1, part1_elapsed) = aoc_common::timed(|| day14::part1(count, &scoreboard, window));
+    let (part2, part2_elapsed) = aoc_common::timed(|| day14::part2(&recipes, &scoreboard));
+
+    let report = Report {
+        parse_elapsed,
+        answers: vec![
+            Answer::new(1, &part1, part1_elapsed),
+            Answer::new(2, &part2, part2_elapsed),
+        ],
+    };
+    options.print(day14::Day14::DAY, &report);
 
-    println!("part 2: {}", answer);
+    Ok(())
 }
 
 fn main() {
-    part1();
-    part2();
+    if let Err(e) = run() {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,33 @@
+use aoc_common::Rng;
+
+/// A walled cave `size` squares wide (at least seven) and two thirds as tall, with an equal
+/// number of elves and goblins.
+///
+/// Pillars only go on a sparse lattice, so every open square stays reachable.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(7);
+    let height = (width * 2 / 3).max(7);
+    let mut map = vec![vec!['.'; width]; height];
+    for (y, row) in map.iter_mut().enumerate() {
+        for (x, cell) in row.iter_mut().enumerate() {
+            let border = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+            if border || (x % 4 == 2 && y % 4 == 2 && rng.chance(50)) {
+                *cell = '#';
+            }
+        }
+    }
+
+    let units = (width * height / 40).max(2);
+    let mut placed = 0;
+    while placed < units {
+        let (x, y) = (1 + rng.index(width - 2), 1 + rng.index(height - 2));
+        if map[y][x] == '.' {
+            map[y][x] = if placed % 2 == 0 { 'E' } else { 'G' };
+            placed += 1;
+        }
+    }
+
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -0,0 +1,554 @@
+use aoc_common::{Frames, Grid, ParseError, Point, Render, Rgb, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::{self, Display};
+use tracing::{debug, info, info_span};
+
+mod generate;
+
+pub use crate::generate::generate;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd)]
+pub enum Race {
+    Elf,
+    Goblin,
+}
+
+impl Display for Race {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Race::Elf => write!(formatter, "E"),
+            Race::Goblin => write!(formatter, "G"),
+        }
+    }
+}
+
+impl Race {
+    fn enemy(self) -> Race {
+        match self {
+            Race::Elf => Race::Goblin,
+            Race::Goblin => Race::Elf,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd)]
+pub struct Character {
+    position: Point<usize>,
+    race: Race,
+    hit_points: i16,
+    attack_power: i16,
+    dead: bool,
+}
+
+impl Display for Character {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(formatter, "{}({})", self.race, self.hit_points)
+    }
+}
+
+impl Character {
+    fn new(race: Race, position: Point<usize>) -> Character {
+        Character {
+            position,
+            race,
+            hit_points: 200,
+            attack_power: 3,
+            dead: false,
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.dead
+    }
+
+    fn take_hit(&mut self, attack: i16) {
+        self.hit_points -= attack;
+        if self.hit_points <= 0 {
+            self.dead = true;
+        }
+    }
+}
+
+struct Simulation<'a> {
+    characters: Vec<Character>,
+    grid: &'a Grid<bool>,
+}
+
+impl<'a> Display for Simulation<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let character_map = self
+            .characters
+            .iter()
+            .map(|&character| (character.position, character))
+            .collect::<HashMap<Point<usize>, Character>>();
+
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                let point = Point(x, y);
+                if self.grid[point] {
+                    if let Some(Character { race, .. }) = character_map.get(&point) {
+                        write!(formatter, "{}", race)?;
+                    } else {
+                        write!(formatter, ".")?;
+                    }
+                } else {
+                    write!(formatter, "#")?;
+                }
+            }
+
+            let mut row_characters = character_map
+                .iter()
+                .filter(|(Point(_, py), _)| *py == y)
+                .map(|(_, &character)| character)
+                .collect::<Vec<Character>>();
+            row_characters.sort_unstable();
+            write!(
+                formatter,
+                "   {}",
+                row_characters
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+
+            writeln!(formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+const WALL: Rgb = [48, 48, 48];
+const FLOOR: Rgb = [176, 160, 128];
+
+impl<'a> Simulation<'a> {
+    /// The cave as an image, with elves in green and goblins in red, fading as they are hurt.
+    fn draw(&self) -> Grid<Rgb> {
+        let mut image = Grid::new(self.grid.width(), self.grid.height(), WALL);
+        for (point, &open) in self.grid.iter() {
+            if open {
+                image[point] = FLOOR;
+            }
+        }
+        for character in self.characters.iter().filter(|c| c.is_alive()) {
+            let shade = 55 + character.hit_points.clamp(0, 200) as u8;
+            image[character.position] = match character.race {
+                Race::Elf => [0, shade, 0],
+                Race::Goblin => [shade, 0, 0],
+            };
+        }
+
+        image
+    }
+
+    fn new(grid: &'a Grid<bool>, characters: Vec<Character>) -> Simulation<'a> {
+        Simulation { grid, characters }
+    }
+
+    fn is_complete(&self) -> bool {
+        match self.characters.first() {
+            Some(&Character { race, .. }) => self
+                .characters
+                .iter()
+                .filter(|c| c.is_alive())
+                .all(|c| c.race == race),
+            None => true,
+        }
+    }
+
+    fn remove_dead(&mut self) {
+        self.characters.retain(|c| c.is_alive());
+    }
+
+    fn find_enemy_to_attack(&self, character: &Character) -> Option<usize> {
+        let character_map = self
+            .characters
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_alive())
+            .filter(|(_, c)| character.race.enemy() == c.race)
+            .map(|(i, &character)| (character.position, i))
+            .collect::<HashMap<Point<usize>, usize>>();
+
+        character
+            .position
+            .neighbors4()
+            .filter_map(|point| character_map.get(&point).map(|&i| (i, self.characters[i])))
+            .min_by_key(|(_, c)| (c.hit_points, c.position))
+            .map(|(i, _)| i)
+    }
+
+    fn find_move(&self, Character { position, race, .. }: &Character) -> Option<Point<usize>> {
+        let targets = self
+            .characters
+            .iter()
+            .filter(|c| c.is_alive())
+            .filter(|c| c.race == race.enemy())
+            .map(|c| c.position)
+            .collect::<HashSet<Point<usize>>>();
+
+        let blocked = self
+            .characters
+            .iter()
+            .filter(|c| c.is_alive())
+            .map(|c| c.position)
+            .collect::<HashSet<Point<usize>>>();
+
+        let mut came_from = HashMap::new();
+        let mut search = BinaryHeap::new();
+        search.push(Reverse((0, *position)));
+
+        while let Some(Reverse((distance, next))) = search.pop() {
+            let neighbors = next
+                .neighbors4()
+                .filter(|p| !came_from.contains_key(p))
+                .filter(|&p| self.grid.get(p).cloned().unwrap_or_default())
+                .collect::<Vec<Point<usize>>>();
+            for neighbor in neighbors {
+                if targets.contains(&neighbor) {
+                    return came_from.remove(&next);
+                } else if blocked.contains(&neighbor) {
+                    continue;
+                } else {
+                    let prev = came_from.get(&next).unwrap_or(&neighbor);
+                    came_from.insert(neighbor, *prev);
+                    search.push(Reverse((distance + 1, neighbor)));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn total_health(&self) -> i64 {
+        self.characters
+            .iter()
+            .filter(|c| c.is_alive())
+            .map(|c| i64::from(c.hit_points))
+            .sum()
+    }
+
+    fn tick(&mut self) -> bool {
+        self.characters
+            .sort_by_key(|&Character { position, .. }| position);
+        for i in 0..self.characters.len() {
+            if !self.characters[i].is_alive() {
+                continue;
+            }
+
+            if self.is_complete() {
+                self.remove_dead();
+                return true;
+            }
+
+            let next_move = self.find_move(&self.characters[i]);
+            if let Some(point) = next_move {
+                self.characters[i].position = point;
+            }
+
+            let enemy = self.find_enemy_to_attack(&self.characters[i]);
+            if let Some(enemy_index) = enemy {
+                let attack = self.characters[i].attack_power;
+                self.characters[enemy_index].take_hit(attack);
+            }
+        }
+
+        self.remove_dead();
+
+        false
+    }
+
+    /// Runs round number `round`, counting from one, and logs the casualties and who is left.
+    fn round(&mut self, round: i64) -> bool {
+        let before = self.characters.len();
+        let complete = self.tick();
+        let elves = self
+            .characters
+            .iter()
+            .filter(|c| c.race == Race::Elf)
+            .count();
+        let goblins = self.characters.len() - elves;
+        debug!(
+            round,
+            casualties = before - self.characters.len(),
+            elves,
+            goblins,
+            "round"
+        );
+
+        complete
+    }
+}
+
+pub struct Cave {
+    grid: Grid<bool>,
+    characters: Vec<Character>,
+}
+
+pub fn parse(input: &str) -> Result<Cave, ParseError> {
+    let mut characters = vec![];
+    let grid = Grid::parse(input, |point, c| match c {
+        'E' | 'G' => {
+            let race = match c {
+                'E' => Race::Elf,
+                'G' => Race::Goblin,
+                _ => unreachable!(),
+            };
+
+            characters.push(Character::new(race, point));
+            Ok(true)
+        }
+        '.' => Ok(true),
+        '#' => Ok(false),
+        _ => {
+            let Point(x, y) = point;
+            let found = Some(c.to_string());
+            Err(ParseError::new(y + 1, x + 1, "`#`, `.`, `E` or `G`", found))
+        }
+    })?;
+
+    Ok(Cave { grid, characters })
+}
+
+pub fn part1(Cave { grid, characters }: &Cave) -> i64 {
+    let characters = characters.to_vec();
+    let mut simulation = Simulation::new(grid, characters);
+    for t in 0.. {
+        if simulation.round(t + 1) {
+            return t * simulation.total_health();
+        }
+    }
+
+    unreachable!()
+}
+
+/// The outcome of the combat with elves hitting for `attack`, or `None` if an elf dies.
+fn flawless_victory(Cave { grid, characters }: &Cave, attack: i16) -> Option<i64> {
+    let total_elf_count = characters.iter().filter(|c| c.race == Race::Elf).count();
+    let characters = characters
+        .iter()
+        .map(|&c| {
+            if c.race == Race::Elf {
+                Character {
+                    attack_power: attack,
+                    ..c
+                }
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let _span = info_span!("combat", attack).entered();
+    info!("trying attack power");
+    let mut simulation = Simulation::new(grid, characters);
+    for t in 0.. {
+        if simulation.round(t + 1) {
+            let elf_count = simulation
+                .characters
+                .iter()
+                .filter(|c| c.race == Race::Elf)
+                .count();
+            info!(elves_lost = total_elf_count - elf_count, "combat over");
+            if elf_count == total_elf_count {
+                return Some(t * simulation.total_health());
+            } else {
+                return None;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part2(cave: &Cave) -> i64 {
+    // Tries as many attack powers at once as there are jobs, taking the lowest that works.
+    // Past 200 every hit kills, so there is no point trying more at once than that.
+    let jobs = aoc_common::jobs().min(200) as i16;
+    for first in (4..).step_by(jobs as usize) {
+        let attacks = (first..first + jobs).collect::<Vec<i16>>();
+        let outcomes = aoc_common::par_map(attacks, |attack| flawless_victory(cave, attack));
+        if let Some(outcome) = outcomes.into_iter().flatten().next() {
+            return outcome;
+        }
+    }
+
+    unreachable!()
+}
+
+/// Draws the cave after every round of the combat from part 1.
+pub fn render(Cave { grid, characters }: &Cave, frames: &mut Frames) -> aoc_common::Result<()> {
+    let mut simulation = Simulation::new(grid, characters.to_vec());
+    loop {
+        frames.write(&simulation.draw())?;
+        if frames.is_full() {
+            break;
+        }
+
+        if simulation.tick() {
+            frames.write(&simulation.draw())?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Cave;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+impl Render for Day15 {
+    fn render(input: &Self::Input, frames: &mut Frames) -> aoc_common::Result<()> {
+        render(input, frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+
+    const EXAMPLES: [(&str, i64); 6] = [
+        (
+            "\
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
+",
+            27730,
+        ),
+        (
+            "\
+#######
+#G..#E#
+#E#E.E#
+#G.##.#
+#...#E#
+#...E.#
+#######
+",
+            36334,
+        ),
+        (
+            "\
+#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######
+",
+            39514,
+        ),
+        (
+            "\
+#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######
+",
+            27755,
+        ),
+        (
+            "\
+#######
+#.E...#
+#.#..G#
+#.###.#
+#E#G#G#
+#...#G#
+#######
+",
+            28944,
+        ),
+        (
+            "\
+#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########
+",
+            18740,
+        ),
+    ];
+
+    #[test]
+    fn part1_examples() {
+        for &(map, outcome) in EXAMPLES.iter() {
+            assert_eq!(part1(&parse(map).unwrap()), outcome, "\n{}", map);
+        }
+    }
+
+    #[test]
+    fn part2_examples() {
+        // The second example has no outcome for part 2.
+        let outcomes = [
+            Some(4988),
+            None,
+            Some(31284),
+            Some(3478),
+            Some(6474),
+            Some(1140),
+        ];
+        for (&(map, _), &outcome) in EXAMPLES.iter().zip(outcomes.iter()) {
+            if let Some(outcome) = outcome {
+                assert_eq!(part2(&parse(map).unwrap()), outcome, "\n{}", map);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_rejects_unknown_cells() {
+        assert_eq!(
+            parse("###\n#X#\n###\n").err(),
+            Some(ParseError::new(
+                2,
+                2,
+                "`#`, `.`, `E` or `G`",
+                Some("X".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn generated_cave_has_both_races() {
+        let cave = parse(&generate(&mut Rng::new(1), 12)).unwrap();
+        assert_eq!(cave.grid.width(), 12);
+        assert!(cave.characters.iter().any(|c| c.race == Race::Elf));
+        assert!(cave.characters.iter().any(|c| c.race == Race::Goblin));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Instruction, ALL_OPCODES};
+use aoc_common::Rng;
+use std::fmt::Write;
+
+fn register(rng: &mut Rng) -> usize {
+    rng.index(4)
+}
+
+/// Writes a sample of `opcode` (an index into `ALL_OPCODES`) numbered as `codes` says.
+///
+/// With `unambiguous` it keeps trying until the sample matches no other opcode.
+fn write_sample(rng: &mut Rng, input: &mut String, codes: &[u8], opcode: usize, unambiguous: bool) {
+    loop {
+        let (a, b, c) = (register(rng), register(rng), register(rng));
+        let before = (0..4).map(|_| register(rng)).collect::<Vec<usize>>();
+        let after = Instruction(ALL_OPCODES[opcode], a, b, c).apply(&before);
+        let matches = ALL_OPCODES
+            .iter()
+            .filter(|&&other| Instruction(other, a, b, c).apply(&before) == after)
+            .count();
+
+        if !unambiguous || matches == 1 {
+            writeln!(input, "Before: {:?}", before).unwrap();
+            writeln!(input, "{} {} {} {}", codes[opcode], a, b, c).unwrap();
+            writeln!(input, "After:  {:?}\n", after).unwrap();
+            return;
+        }
+    }
+}
+
+/// A manual with `size` random samples followed by a program of `size` instructions, using a
+/// random numbering of the opcodes.
+///
+/// The samples end with one for each opcode that fits no other, so the numbering can always be
+/// worked out. Every operand is a valid register, so any instruction can run.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut codes = (0..16).collect::<Vec<u8>>();
+    rng.shuffle(&mut codes);
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let opcode = rng.index(ALL_OPCODES.len());
+        write_sample(rng, &mut input, &codes, opcode, false);
+    }
+    for opcode in 0..ALL_OPCODES.len() {
+        write_sample(rng, &mut input, &codes, opcode, true);
+    }
+
+    input.push_str("\n\n");
+    for _ in 0..size {
+        let code = *rng.choose(&codes);
+        let (a, b, c) = (register(rng), register(rng), register(rng));
+        writeln!(input, "{} {} {} {}", code, a, b, c).unwrap();
+    }
+
+    input
+}
@@ -0,0 +1,388 @@
+use aoc_common::{ParseError, Scanner, Solution};
+use std::collections::{HashMap, HashSet};
+
+mod generate;
+
+pub use crate::generate::generate;
+
+type Registers = Vec<usize>;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Instruction(Opcode, usize, usize, usize);
+
+impl Instruction {
+    fn apply(&self, before: &[usize]) -> Registers {
+        let mut after = before.to_vec();
+        match *self {
+            Instruction(Opcode::Addr, a, b, c) => after[c] = before[a] + before[b],
+            Instruction(Opcode::Addi, a, b, c) => after[c] = before[a] + b,
+            Instruction(Opcode::Mulr, a, b, c) => after[c] = before[a] * before[b],
+            Instruction(Opcode::Muli, a, b, c) => after[c] = before[a] * b,
+            Instruction(Opcode::Banr, a, b, c) => after[c] = before[a] & before[b],
+            Instruction(Opcode::Bani, a, b, c) => after[c] = before[a] & b,
+            Instruction(Opcode::Borr, a, b, c) => after[c] = before[a] | before[b],
+            Instruction(Opcode::Bori, a, b, c) => after[c] = before[a] | b,
+            Instruction(Opcode::Setr, a, _, c) => after[c] = before[a],
+            Instruction(Opcode::Seti, a, _, c) => after[c] = a,
+            Instruction(Opcode::Gtir, a, b, c) => after[c] = if a > before[b] { 1 } else { 0 },
+            Instruction(Opcode::Gtri, a, b, c) => after[c] = if before[a] > b { 1 } else { 0 },
+            Instruction(Opcode::Gtrr, a, b, c) => {
+                after[c] = if before[a] > before[b] { 1 } else { 0 }
+            }
+            Instruction(Opcode::Eqir, a, b, c) => after[c] = if a == before[b] { 1 } else { 0 },
+            Instruction(Opcode::Eqri, a, b, c) => after[c] = if before[a] == b { 1 } else { 0 },
+            Instruction(Opcode::Eqrr, a, b, c) => {
+                after[c] = if before[a] == before[b] { 1 } else { 0 }
+            }
+        };
+
+        after
+    }
+}
+
+const ALL_OPCODES: [Opcode; 16] = [
+    Opcode::Addr,
+    Opcode::Addi,
+    Opcode::Mulr,
+    Opcode::Muli,
+    Opcode::Banr,
+    Opcode::Bani,
+    Opcode::Borr,
+    Opcode::Bori,
+    Opcode::Setr,
+    Opcode::Seti,
+    Opcode::Gtir,
+    Opcode::Gtri,
+    Opcode::Gtrr,
+    Opcode::Eqir,
+    Opcode::Eqri,
+    Opcode::Eqrr,
+];
+
+#[derive(Debug, PartialEq)]
+struct Example {
+    before: Registers,
+    after: Registers,
+    instruction: (u8, usize, usize, usize),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Manual {
+    examples: Vec<Example>,
+    program: Vec<(u8, usize, usize, usize)>,
+}
+
+fn parse_registers(scanner: &mut Scanner) -> Result<Registers, ParseError> {
+    scanner.expect("[")?;
+    let mut registers = vec![scanner.integer("register value")?];
+    for _ in 0..3 {
+        scanner.expect(",")?;
+        registers.push(scanner.integer("register value")?);
+    }
+    scanner.expect("]")?;
+
+    Ok(registers)
+}
+
+fn parse_instruction(scanner: &mut Scanner) -> Result<(u8, usize, usize, usize), ParseError> {
+    let code = scanner.integer("opcode")?;
+    let a = scanner.integer("operand")?;
+    let b = scanner.integer("operand")?;
+    let c = scanner.integer("operand")?;
+
+    Ok((code, a, b, c))
+}
+
+fn parse_line<'a, I, T, F>(
+    lines: &mut I,
+    end: usize,
+    expected: &str,
+    parse: F,
+) -> Result<T, ParseError>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+    F: FnOnce(&mut Scanner) -> Result<T, ParseError>,
+{
+    let (i, line) = lines
+        .next()
+        .ok_or_else(|| ParseError::new(end, 1, expected, None))?;
+    let mut scanner = Scanner::new(line);
+    let value = parse(&mut scanner)
+        .and_then(|value| scanner.end().map(|_| value))
+        .map_err(|e| e.on_line(i + 1))?;
+
+    Ok(value)
+}
+
+pub fn parse(input: &str) -> Result<Manual, ParseError> {
+    let end = input.lines().count() + 1;
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .peekable();
+
+    let mut examples = vec![];
+    while let Some((_, line)) = lines.peek() {
+        if !line.starts_with("Before:") {
+            break;
+        }
+
+        let before = parse_line(&mut lines, end, "`Before:`", |scanner| {
+            scanner.expect("Before:")?;
+            parse_registers(scanner)
+        })?;
+        let instruction = parse_line(&mut lines, end, "opcode", parse_instruction)?;
+        let after = parse_line(&mut lines, end, "`After:`", |scanner| {
+            scanner.expect("After:")?;
+            parse_registers(scanner)
+        })?;
+
+        examples.push(Example {
+            before,
+            after,
+            instruction,
+        });
+    }
+
+    let mut program = vec![];
+    while lines.peek().is_some() {
+        program.push(parse_line(&mut lines, end, "opcode", parse_instruction)?);
+    }
+
+    Ok(Manual { examples, program })
+}
+
+pub fn part1(Manual { examples, .. }: &Manual) -> usize {
+    examples
+        .iter()
+        .map(
+            |Example {
+                 before,
+                 after,
+                 instruction: (_, a, b, c),
+             }| {
+                ALL_OPCODES
+                    .iter()
+                    .map(|opcode| Instruction(*opcode, *a, *b, *c))
+                    .filter(|instruction| &instruction.apply(before) == after)
+                    .count()
+            },
+        )
+        .filter(|&count| count >= 3)
+        .count()
+}
+
+pub fn part2(Manual { examples, program }: &Manual) -> usize {
+    let mut candidates = examples
+        .iter()
+        .map(
+            |Example {
+                 before,
+                 after,
+                 instruction: (code, a, b, c),
+             }| {
+                (
+                    *code,
+                    ALL_OPCODES
+                        .iter()
+                        .map(|opcode| (opcode, Instruction(*opcode, *a, *b, *c)))
+                        .filter(|(_, instruction)| &instruction.apply(before) == after)
+                        .map(|(&opcode, _)| opcode)
+                        .collect(),
+                )
+            },
+        )
+        .collect::<HashMap<u8, HashSet<Opcode>>>();
+
+    let mut opcode_mapping = HashMap::new();
+    loop {
+        for code in opcode_mapping.keys() {
+            candidates.remove(code);
+        }
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        let used_opcodes = opcode_mapping
+            .values()
+            .cloned()
+            .collect::<HashSet<Opcode>>();
+        let filtered_candidates = candidates
+            .iter()
+            .map(|(code, opcodes)| {
+                (
+                    code,
+                    opcodes
+                        .iter()
+                        .filter(|opcode| !used_opcodes.contains(opcode))
+                        .cloned()
+                        .collect::<Vec<Opcode>>(),
+                )
+            })
+            .filter(|(_, opcodes)| opcodes.len() == 1)
+            .map(|(code, opcodes)| (*code, opcodes[0]))
+            .collect::<HashMap<u8, Opcode>>();
+
+        opcode_mapping.extend(filtered_candidates);
+    }
+
+    let instructions = program
+        .iter()
+        .map(|&(code, a, b, c)| Instruction(opcode_mapping[&code], a, b, c));
+
+    let mut registers = vec![0, 0, 0, 0];
+    for instruction in instructions {
+        registers = instruction.apply(&registers);
+    }
+
+    registers[0]
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 1);
+    }
+
+    #[test]
+    fn example_behaves_like_three_opcodes() {
+        let before = [3, 2, 1, 1];
+        let opcodes = ALL_OPCODES
+            .iter()
+            .filter(|&&opcode| Instruction(opcode, 2, 1, 2).apply(&before) == [3, 2, 2, 1])
+            .cloned()
+            .collect::<Vec<Opcode>>();
+        assert_eq!(opcodes, vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti]);
+    }
+
+    #[test]
+    fn parse_splits_examples_from_program() {
+        let manual = parse(&format!("{}\n\n\n9 2 1 2\n5 0 3 1\n", EXAMPLE)).unwrap();
+        assert_eq!(manual.examples.len(), 1);
+        assert_eq!(manual.program, vec![(9, 2, 1, 2), (5, 0, 3, 1)]);
+    }
+
+    #[test]
+    fn parse_rejects_truncated_examples() {
+        assert_eq!(
+            parse("Before: [3, 2, 1, 1]\n9 2 1 2\n").err(),
+            Some(ParseError::new(3, 1, "`After:`", None))
+        );
+        assert_eq!(
+            parse("Before: [3, 2, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n").err(),
+            Some(ParseError::new(1, 17, "`,`", Some("]".to_string())))
+        );
+    }
+
+    #[test]
+    fn generated_manual_resolves_every_opcode() {
+        let manual = parse(&generate(&mut Rng::new(1), 100)).unwrap();
+        assert_eq!(manual.examples.len(), 116);
+        assert_eq!(manual.program.len(), 100);
+        part2(&manual);
+    }
+
+    fn manual() -> impl Strategy<Value = Manual> {
+        let registers = || prop::collection::vec(any::<usize>(), 4);
+        let instruction = || any::<(u8, usize, usize, usize)>();
+        let example =
+            (registers(), instruction(), registers()).prop_map(|(before, instruction, after)| {
+                Example {
+                    before,
+                    after,
+                    instruction,
+                }
+            });
+
+        (
+            prop::collection::vec(example, 0..5),
+            prop::collection::vec(instruction(), 0..5),
+        )
+            .prop_map(|(examples, program)| Manual { examples, program })
+    }
+
+    fn encode(Manual { examples, program }: &Manual) -> String {
+        let mut input = String::new();
+        for example in examples {
+            let (code, a, b, c) = example.instruction;
+            input.push_str(&format!(
+                "Before: {:?}\n{} {} {} {}\nAfter:  {:?}\n\n",
+                example.before, code, a, b, c, example.after
+            ));
+        }
+
+        input.push_str("\n\n");
+        for (code, a, b, c) in program {
+            input.push_str(&format!("{} {} {} {}\n", code, a, b, c));
+        }
+
+        input
+    }
+
+    proptest! {
+        #[test]
+        fn manual_round_trips(manual in manual()) {
+            prop_assert_eq!(parse(&encode(&manual)), Ok(manual));
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = parse(&input);
+        }
+    }
+}
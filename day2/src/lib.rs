@@ -0,0 +1,422 @@
+extern crate aoc_common;
+extern crate unicode_segmentation;
+
+use aoc_common::Solution;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
+use std::io::BufRead;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub fn parse(input: &str) -> Vec<String> {
+    input.lines().map(|s| s.to_string()).collect()
+}
+
+pub fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Vec<String>> {
+    Ok(reader.lines().collect::<Result<_, _>>()?)
+}
+
+/// What counts as a single letter of an ID.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Letters {
+    /// Each `char`, which is what the puzzle's ASCII IDs need.
+    Chars,
+    /// Each extended grapheme cluster, so an accented letter made of several code points counts
+    /// as one letter.
+    Graphemes,
+}
+
+/// Every number of times that some letter appears.
+fn letter_multiplicities<T: Hash + Eq, I: Iterator<Item = T>>(letters: I) -> HashSet<usize> {
+    let freqs = letters.fold(HashMap::new(), |mut freqs, letter| {
+        *freqs.entry(letter).or_insert(0) += 1;
+        freqs
+    });
+
+    freqs.values().cloned().collect()
+}
+
+/// Counts, for each of `multiplicities`, how many IDs have some letter appearing exactly that
+/// many times.
+pub fn letter_counts(
+    input: &[String],
+    multiplicities: &[usize],
+    letters: Letters,
+) -> BTreeMap<usize, usize> {
+    let mut counts = multiplicities
+        .iter()
+        .map(|&m| (m, 0))
+        .collect::<BTreeMap<usize, usize>>();
+
+    for item in input {
+        let found = match letters {
+            Letters::Chars => letter_multiplicities(item.chars()),
+            Letters::Graphemes => letter_multiplicities(item.graphemes(true)),
+        };
+        for (m, count) in counts.iter_mut() {
+            if found.contains(m) {
+                *count += 1;
+            }
+        }
+    }
+
+    counts
+}
+
+/// Multiplies the counts from `letter_counts` together.
+pub fn checksum(counts: &BTreeMap<usize, usize>) -> usize {
+    counts.values().product()
+}
+
+pub fn part1(input: &[String]) -> usize {
+    checksum(&letter_counts(input, &[2, 3], Letters::Chars))
+}
+
+/// The multiplier for the rolling hashes of IDs.
+const HASH_BASE: u64 = 1_000_003;
+
+fn extend_hash(hash: u64, c: char) -> u64 {
+    hash.wrapping_mul(HASH_BASE)
+        .wrapping_add(u64::from(c as u32) + 1)
+}
+
+/// The number of positions where two IDs have different characters.
+fn mismatches(item1: &str, item2: &str) -> usize {
+    item1
+        .chars()
+        .zip(item2.chars())
+        .filter(|(c1, c2)| c1 != c2)
+        .count()
+}
+
+/// Finds an ID that differs from an earlier one of the same length in exactly one position,
+/// and returns the indexes of the earlier ID and then that one.
+///
+/// Each ID is hashed once for every position with that position left out, so two IDs that
+/// differ only there get the same hash. Prefix and suffix hashes make each of those constant
+/// time, for O(n·L) overall. Every ID is kept under each of its hashes and checked character
+/// by character against the others there, so a collision costs an extra check but can neither
+/// give a wrong answer nor hide the right one.
+pub fn one_apart(input: &[String]) -> Option<(usize, usize)> {
+    let mut seen = HashSet::new();
+    let mut masked = HashMap::<_, Vec<usize>>::new();
+
+    for (j, item) in input.iter().enumerate() {
+        // A copy of an earlier ID matches it at every position without being one apart.
+        if !seen.insert(item) {
+            continue;
+        }
+
+        let chars = item.chars().collect::<Vec<char>>();
+        let len = chars.len();
+        let mut prefixes = Vec::with_capacity(len + 1);
+        prefixes.push(0);
+        for &c in &chars {
+            let last = prefixes[prefixes.len() - 1];
+            prefixes.push(extend_hash(last, c));
+        }
+        let mut suffixes = vec![0; len + 1];
+        for i in (0..len).rev() {
+            suffixes[i] = extend_hash(suffixes[i + 1], chars[i]);
+        }
+
+        for i in 0..len {
+            let others = masked
+                .entry((len, i, prefixes[i], suffixes[i + 1]))
+                .or_insert_with(Vec::new);
+            if let Some(&other) = others
+                .iter()
+                .find(|&&other| mismatches(&input[other], item) == 1)
+            {
+                return Some((other, j));
+            }
+            others.push(j);
+        }
+    }
+
+    None
+}
+
+pub fn part2(input: &[String]) -> aoc_common::Result<String> {
+    let (i, j) = one_apart(input).ok_or("no two box IDs differ by exactly one character")?;
+    Ok(input[i]
+        .chars()
+        .zip(input[j].chars())
+        .filter(|(c1, c2)| c1 == c2)
+        .map(|(c, _)| c)
+        .collect())
+}
+
+/// Checks that every ID is as long as the first and returns that length, since IDs of
+/// different lengths can't be compared position by position.
+fn check_lengths(input: &[String]) -> aoc_common::Result<usize> {
+    let len = input.first().map_or(0, |item| item.chars().count());
+    for (line, item) in input.iter().enumerate() {
+        let item_len = item.chars().count();
+        if item_len != len {
+            return Err(format!(
+                "line {}: `{}` has {} characters, but the first ID has {}",
+                line + 1,
+                item,
+                item_len,
+                len
+            )
+            .into());
+        }
+    }
+
+    Ok(len)
+}
+
+/// Every pair of IDs that differ in at most `k` positions, as indexes in increasing order.
+///
+/// IDs that differ in at most `k` positions agree on at least one of any `k + 1` blocks their
+/// positions are split into, so only IDs that share a block are compared.
+pub fn pairs_within(input: &[String], k: usize) -> aoc_common::Result<Vec<(usize, usize)>> {
+    let len = check_lengths(input)?;
+    let items = input
+        .iter()
+        .map(|item| item.chars().collect::<Vec<char>>())
+        .collect::<Vec<_>>();
+    let blocks = k + 1;
+    let mut pairs = BTreeSet::new();
+
+    for block in 0..blocks {
+        let positions = block * len / blocks..(block + 1) * len / blocks;
+        let mut buckets = HashMap::new();
+        for (j, item) in items.iter().enumerate() {
+            buckets
+                .entry(&item[positions.clone()])
+                .or_insert_with(Vec::new)
+                .push(j);
+        }
+
+        for bucket in buckets.values() {
+            for (n, &i) in bucket.iter().enumerate() {
+                for &j in &bucket[n + 1..] {
+                    if !pairs.contains(&(i, j)) && mismatches(&input[i], &input[j]) <= k {
+                        pairs.insert((i, j));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(pairs.into_iter().collect())
+}
+
+/// Disjoint sets of indexes, merged by size with paths compressed on lookup.
+struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> DisjointSet {
+        DisjointSet {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut index = index;
+        while self.parents[index] != root {
+            let parent = self.parents[index];
+            self.parents[index] = root;
+            index = parent;
+        }
+
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+
+        let (small, large) = if self.sizes[a] < self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+    }
+}
+
+/// Groups the IDs joined by chains of pairs that differ in at most `k` positions, largest group
+/// first. An ID that isn't within `k` of any other is a group of its own.
+pub fn clusters(input: &[String], k: usize) -> aoc_common::Result<Vec<Vec<usize>>> {
+    let mut sets = DisjointSet::new(input.len());
+    for (i, j) in pairs_within(input, k)? {
+        sets.union(i, j);
+    }
+
+    let mut clusters = HashMap::new();
+    for i in 0..input.len() {
+        clusters
+            .entry(sets.find(i))
+            .or_insert_with(Vec::new)
+            .push(i);
+    }
+
+    let mut clusters = clusters.into_values().collect::<Vec<_>>();
+    clusters.sort_by_key(|cluster| (Reverse(cluster.len()), cluster[0]));
+    Ok(clusters)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input))
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Self::Input> {
+        parse_reader(reader)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+
+    #[test]
+    fn part1_example() {
+        let input = parse("abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab\n");
+        assert_eq!(part1(&input), 12);
+    }
+
+    #[test]
+    fn letter_counts_for_any_multiplicities() {
+        let input = parse("abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab\n");
+        let counts = letter_counts(&input, &[1, 2, 3, 4], Letters::Chars);
+        let expected = vec![(1, 6), (2, 4), (3, 3), (4, 0)];
+        assert_eq!(counts, expected.into_iter().collect());
+        assert_eq!(checksum(&counts), 0);
+        assert_eq!(checksum(&letter_counts(&input, &[], Letters::Chars)), 1);
+    }
+
+    #[test]
+    fn letter_counts_by_grapheme() {
+        // Each "e\u{301}" is an e followed by a combining acute accent.
+        let input = vec!["e\u{301}e\u{301}xe".to_string()];
+        let by_char = letter_counts(&input, &[2, 3], Letters::Chars);
+        assert_eq!(by_char, vec![(2, 1), (3, 1)].into_iter().collect());
+        let by_grapheme = letter_counts(&input, &[2, 3], Letters::Graphemes);
+        assert_eq!(by_grapheme, vec![(2, 1), (3, 0)].into_iter().collect());
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = parse("abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n");
+        assert_eq!(part2(&input).unwrap(), "fgij");
+    }
+
+    #[test]
+    fn one_apart_skips_copies_and_other_lengths() {
+        let input = parse("abcd\nabcd\nabc\nabce\n");
+        assert_eq!(one_apart(&input), Some((0, 3)));
+        assert_eq!(one_apart(&parse("abc\nabd\n")), Some((0, 1)));
+        assert_eq!(one_apart(&parse("abc\nabc\nab\nabcd\nbcd\n")), None);
+    }
+
+    #[test]
+    fn one_apart_looks_past_hash_collisions() {
+        // Thue-Morse strings of length 1024 and their complements have the same hash for
+        // any odd base, so the first two IDs share a hash with their last letter left out.
+        let thue_morse = (0..1024u32)
+            .map(|i| if i.count_ones() % 2 == 0 { 'a' } else { 'b' })
+            .collect::<String>();
+        let complement = thue_morse
+            .chars()
+            .map(|c| if c == 'a' { 'b' } else { 'a' })
+            .collect::<String>();
+        let input = vec![
+            thue_morse + "x",
+            complement.clone() + "x",
+            complement + "y",
+        ];
+        assert_eq!(one_apart(&input), Some((1, 2)));
+        assert_eq!(part2(&input).unwrap(), input[1][..1024]);
+    }
+
+    #[test]
+    fn part2_rejects_ids_without_a_match() {
+        assert!(part2(&parse("abcde\nfghij\n")).is_err());
+        assert!(part2(&[]).is_err());
+    }
+
+    #[test]
+    fn pairs_within_any_distance() {
+        let input = parse("abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n");
+        assert_eq!(pairs_within(&input, 0).unwrap(), vec![]);
+        assert_eq!(pairs_within(&input, 1).unwrap(), vec![(1, 4)]);
+        assert_eq!(pairs_within(&input, 2).unwrap(), vec![(0, 5), (1, 4)]);
+        assert_eq!(pairs_within(&input, 5).unwrap().len(), 21);
+    }
+
+    #[test]
+    fn pairs_within_match_comparing_every_pair() {
+        let mut rng = Rng::new(2);
+        let input = (0..60)
+            .map(|_| (0..6).map(|_| *rng.choose(&['a', 'b', 'c'])).collect())
+            .collect::<Vec<String>>();
+        for k in 0..4 {
+            let mut expected = vec![];
+            for i in 0..input.len() {
+                for j in i + 1..input.len() {
+                    if mismatches(&input[i], &input[j]) <= k {
+                        expected.push((i, j));
+                    }
+                }
+            }
+            assert_eq!(pairs_within(&input, k).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn pairs_within_rejects_ids_of_different_lengths() {
+        let error = pairs_within(&parse("abc\nabd\nabcd\n"), 1).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: `abcd` has 4 characters, but the first ID has 3"
+        );
+    }
+
+    #[test]
+    fn clusters_join_chains_of_pairs() {
+        let input = parse("aaa\nzzz\naab\nxyz\nabb\nzzy\n");
+        let expected = vec![vec![0, 2, 4], vec![1, 5], vec![3]];
+        assert_eq!(clusters(&input, 1).unwrap(), expected);
+        assert_eq!(clusters(&input, 3).unwrap(), vec![vec![0, 1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn parse_reader_matches_parse() {
+        let input = "abcde\nfghij\n";
+        assert_eq!(parse_reader(input.as_bytes()).unwrap(), parse(input));
+    }
+}
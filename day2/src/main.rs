@@ -1,64 +1,152 @@
-extern crate itertools;
+extern crate aoc_common;
+extern crate day2;
+#[macro_use]
+extern crate serde_json;
 
-use itertools::Itertools;
-use std::collections::HashMap;
+use aoc_common::{Answer, Options, Output, Report, Result, Solution, Source};
+use day2::Letters;
+use serde_json::Value;
+use std::collections::BTreeMap;
 use std::env;
-use std::fs;
-
-fn part1(input: &[String]) {
-    let (two_count, three_count) = input
-        .iter()
-        .map(|item| {
-            item.chars().fold(HashMap::new(), |mut freqs, c| {
-                *freqs.entry(c).or_insert(0) += 1;
-                freqs
-            })
-        })
-        .map(|freqs| {
-            let two = freqs.values().find(|&n| *n == 2).map(|_| 1).unwrap_or(0);
-            let three = freqs.values().find(|&n| *n == 3).map(|_| 1).unwrap_or(0);
-            (two, three)
-        })
-        .fold((0, 0), |(two, three), (x, y)| (two + x, three + y));
+use std::fmt::{self, Display};
+use std::process;
+
+const USAGE: &str = "day2 [FILE|-] [--clusters K] [--checksum M,M,...] [--graphemes]";
 
-    let answer = two_count * three_count;
-    println!("part 1: {}", answer);
+/// How many IDs have a letter appearing exactly `m` times for each multiplicity `m`, and
+/// their product.
+struct Checksum(BTreeMap<usize, usize>);
+
+impl Display for Checksum {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for (m, count) in &self.0 {
+            writeln!(formatter, "{} IDs with a letter {} times", count, m)?;
+        }
+        write!(formatter, "checksum: {}", day2::checksum(&self.0))
+    }
 }
 
-fn part2(input: &[String]) {
-    let answer: String = input
-        .iter()
-        .tuple_combinations()
-        .filter(|(item1, item2)| item1 != item2)
-        .find(|(item1, item2)| {
-            let edits = item1
-                .chars()
-                .zip(item2.chars())
-                .filter(|(c1, c2)| c1 != c2)
-                .count();
-            edits == 1
-        })
-        .map(|(item1, item2)| {
-            item1
-                .chars()
-                .zip(item2.chars())
-                .filter(|(c1, c2)| c1 == c2)
-                .map(|(c, _)| c)
-                .collect()
+impl Output for Checksum {
+    fn data(&self) -> Option<Value> {
+        let counts = self
+            .0
+            .iter()
+            .map(|(m, count)| json!({ "multiplicity": m, "ids": count }))
+            .collect::<Vec<Value>>();
+        Some(json!({ "counts": counts, "checksum": day2::checksum(&self.0) }))
+    }
+}
+
+/// The groups of IDs that differ in at most `k` positions, leaving out IDs on their own.
+struct Clusters {
+    k: usize,
+    groups: Vec<Vec<String>>,
+    alone: usize,
+}
+
+impl Clusters {
+    fn new(input: &[String], k: usize) -> Result<Clusters> {
+        let (groups, alone) = day2::clusters(input, k)?
+            .into_iter()
+            .partition::<Vec<Vec<usize>>, _>(|cluster| cluster.len() > 1);
+        let groups = groups
+            .iter()
+            .map(|cluster| cluster.iter().map(|&i| input[i].clone()).collect())
+            .collect();
+
+        Ok(Clusters {
+            k,
+            groups,
+            alone: alone.len(),
         })
-        .unwrap();
+    }
+}
+
+impl Display for Clusters {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for members in &self.groups {
+            writeln!(formatter, "{}: {}", members.len(), members.join(" "))?;
+        }
+        write!(
+            formatter,
+            "{} clusters within {} of each other, {} IDs on their own",
+            self.groups.len(),
+            self.k,
+            self.alone
+        )
+    }
+}
+
+impl Output for Clusters {
+    fn data(&self) -> Option<Value> {
+        Some(json!({ "k": self.k, "clusters": self.groups, "alone": self.alone }))
+    }
+}
+
+/// Answers with `--checksum` in place of part 1 and `--clusters` in place of part 2, or
+/// with the puzzle's answers when neither is given.
+fn run() -> Result<()> {
+    let mut clusters = None;
+    let mut checksum = None;
+    let mut letters = Letters::Chars;
+
+    let options = Options::parse(env::args().skip(1), USAGE, |flag, args| {
+        match flag {
+            "--clusters" => {
+                let k = args.next().and_then(|k| k.parse().ok());
+                clusters = Some(k.ok_or("--clusters requires a number")?);
+            }
+            "--checksum" => {
+                let value = args.next().ok_or("--checksum requires multiplicities")?;
+                let multiplicities = value
+                    .split(',')
+                    .map(|m| m.trim().parse())
+                    .collect::<std::result::Result<Vec<usize>, _>>()
+                    .map_err(|_| "--checksum requires numbers separated by commas")?;
+                checksum = Some(multiplicities);
+            }
+            "--graphemes" => letters = Letters::Graphemes,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    if letters == Letters::Graphemes && checksum.is_none() {
+        return Err("--graphemes only applies to --checksum".into());
+    }
+
+    let reader = Source::from_arg(options.file.clone()).open()?;
+    if clusters.is_none() && checksum.is_none() {
+        let report = aoc_common::solve::<day2::Day2, _>(reader, None)?;
+        options.print(day2::Day2::DAY, &report);
+        return Ok(());
+    }
+
+    let (input, parse_elapsed) = aoc_common::timed(|| day2::parse_reader(reader));
+    let input = input?;
+    let mut answers = vec![];
+    if let Some(multiplicities) = checksum {
+        let (counts, elapsed) =
+            aoc_common::timed(|| day2::letter_counts(&input, &multiplicities, letters));
+        answers.push(Answer::new(1, &Checksum(counts), elapsed));
+    }
+    if let Some(k) = clusters {
+        let (clusters, elapsed) = aoc_common::timed(|| Clusters::new(&input, k));
+        answers.push(Answer::new(2, &clusters?, elapsed));
+    }
+
+    let report = Report {
+        parse_elapsed,
+        answers,
+    };
+    options.print(day2::Day2::DAY, &report);
 
-    println!("part 2: {}", answer);
+    Ok(())
 }
 
 fn main() {
-    let filename = env::args().nth(1).expect("No file provided");
-    let input = fs::read_to_string(filename)
-        .expect("File to read")
-        .lines()
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
-
-    part1(&input);
-    part2(&input);
+    if let Err(e) = run() {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
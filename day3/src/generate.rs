@@ -0,0 +1,81 @@
+use aoc_common::Rng;
+use Claim;
+
+const FABRIC: i64 = 1000;
+
+fn random_claim(rng: &mut Rng) -> Claim {
+    let (width, height) = (rng.range(10, 30), rng.range(10, 30));
+    Claim {
+        id: 0,
+        x: rng.range(0, FABRIC - width) as u16,
+        y: rng.range(0, FABRIC - height) as u16,
+        width: width as u16,
+        height: height as u16,
+    }
+}
+
+/// A claim the same size as a random one, moved so that it overlaps `other`.
+fn overlapping(rng: &mut Rng, other: &Claim) -> Claim {
+    let claim = random_claim(rng);
+    let place = |rng: &mut Rng, start: u16, len: u16, other_len: u16| {
+        let (start, len, other_len) = (i64::from(start), i64::from(len), i64::from(other_len));
+        let low = (start - len + 1).max(0);
+        let high = (start + other_len - 1).min(FABRIC - len);
+        rng.range(low, high + 1) as u16
+    };
+
+    Claim {
+        x: place(rng, other.x, claim.width, other.width),
+        y: place(rng, other.y, claim.height, other.height),
+        ..claim
+    }
+}
+
+fn overlaps(a: &Claim, b: &Claim) -> bool {
+    a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+}
+
+/// `size` claims (at least three) on a 1000x1000 fabric, exactly one of which overlaps no other.
+///
+/// Claims come in pairs that overlap each other, with a lone last claim overlapping an earlier
+/// one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let intact = random_claim(rng);
+
+    let mut claims: Vec<Claim> = vec![];
+    while claims.len() < size - 1 {
+        let remaining = size - 1 - claims.len();
+        let claim = if claims.len().is_multiple_of(2) && remaining >= 2 {
+            random_claim(rng)
+        } else if claims.len().is_multiple_of(2) {
+            // The last claim has no partner, so it overlaps any earlier claim instead.
+            let other = rng.index(claims.len());
+            overlapping(rng, &claims[other])
+        } else {
+            overlapping(rng, &claims[claims.len() - 1])
+        };
+
+        if !overlaps(&claim, &intact) {
+            claims.push(claim);
+        }
+    }
+
+    let position = rng.index(size);
+    claims.insert(position, intact);
+
+    claims
+        .iter()
+        .enumerate()
+        .map(|(i, claim)| {
+            format!(
+                "#{} @ {},{}: {}x{}\n",
+                i + 1,
+                claim.x,
+                claim.y,
+                claim.width,
+                claim.height
+            )
+        })
+        .collect()
+}
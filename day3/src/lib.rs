@@ -0,0 +1,683 @@
+extern crate aoc_common;
+#[cfg(test)]
+extern crate proptest;
+
+use aoc_common::{Bounds, Frames, Grid, ParseError, Point, Render, Rgb, Scanner, Solution};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::str::FromStr;
+
+mod generate;
+
+pub use generate::generate;
+
+#[derive(Debug, PartialEq)]
+pub struct Claim {
+    pub id: u16,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Claim {
+    /// The column just past the claim's right edge.
+    pub fn right(&self) -> usize {
+        usize::from(self.x) + usize::from(self.width)
+    }
+
+    /// The row just past the claim's bottom edge.
+    pub fn bottom(&self) -> usize {
+        usize::from(self.y) + usize::from(self.height)
+    }
+
+    /// The first and last square inches of the claim, or `None` if it covers none.
+    pub fn bounds(&self) -> Option<Bounds<usize>> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        Some(Bounds {
+            min: Point(self.x.into(), self.y.into()),
+            max: Point(self.right() - 1, self.bottom() - 1),
+        })
+    }
+
+    pub fn contains(&self, Point(x, y): Point<usize>) -> bool {
+        usize::from(self.x) <= x
+            && x < self.right()
+            && usize::from(self.y) <= y
+            && y < self.bottom()
+    }
+
+    /// The square inches covered by both claims, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Claim) -> Option<Bounds<usize>> {
+        let left = self.x.max(other.x).into();
+        let top = self.y.max(other.y).into();
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        if left < right && top < bottom {
+            Some(Bounds {
+                min: Point(left, top),
+                max: Point(right - 1, bottom - 1),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The number of square inches covered by both claims.
+    pub fn overlap_area(&self, other: &Claim) -> usize {
+        self.intersection(other)
+            .map_or(0, |bounds| (bounds.width() + 1) * (bounds.height() + 1))
+    }
+
+    /// Every square inch inside the claim, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let (x, right) = (usize::from(self.x), self.right());
+        (usize::from(self.y)..self.bottom()).flat_map(move |y| (x..right).map(move |x| Point(x, y)))
+    }
+}
+
+impl FromStr for Claim {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(source);
+        scanner.expect("#")?;
+        let id = scanner.integer("claim id")?;
+        scanner.expect("@")?;
+        let x = scanner.integer("left edge")?;
+        scanner.expect(",")?;
+        let y = scanner.integer("top edge")?;
+        scanner.expect(":")?;
+        let width = scanner.integer("width")?;
+        scanner.expect("x")?;
+        let height = scanner.integer("height")?;
+        scanner.end()?;
+
+        Ok(Claim {
+            id,
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+}
+
+impl Display for Claim {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            formatter,
+            "#{} @ {},{}: {}x{}",
+            self.id, self.x, self.y, self.width, self.height
+        )
+    }
+}
+
+/// Parses a claim whose ID isn't in `seen`, and adds its ID.
+fn parse_unique(seen: &mut HashSet<u16>, line: &str) -> Result<Claim, ParseError> {
+    let claim = line.parse::<Claim>()?;
+    if !seen.insert(claim.id) {
+        let mut scanner = Scanner::new(line);
+        scanner.expect("#")?;
+        return Err(scanner.error("a claim id not used before"));
+    }
+
+    Ok(claim)
+}
+
+/// Parses the claims, which must all have different IDs.
+pub fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+    let mut seen = HashSet::new();
+    aoc_common::parse_lines_with(input, |line| parse_unique(&mut seen, line))
+}
+
+pub fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Vec<Claim>> {
+    let mut seen = HashSet::new();
+    aoc_common::read_lines_with(reader, |line| parse_unique(&mut seen, line))
+}
+
+/// How many claims cover each square inch of the fabric, along with the contested area and
+/// the claims that overlap no other, all found in one pass over the claims.
+///
+/// The fabric is cut only along the claims' edges, so claims far apart cost no more than
+/// claims side by side.
+pub struct Fabric {
+    /// Every column where a claim starts or ends, in increasing order.
+    columns: Vec<usize>,
+    /// Every row where a claim starts or ends, in increasing order.
+    rows: Vec<usize>,
+    /// Claims covering each piece, the one from `columns[x]` to `columns[x + 1]` and
+    /// `rows[y]` to `rows[y + 1]` at `Point(x, y)`.
+    counts: Grid<u16>,
+    contested: usize,
+    intact: Vec<u16>,
+}
+
+/// The sorted, distinct edges of the claims along one axis.
+fn edges<F: Fn(&Claim) -> (usize, usize)>(claims: &[Claim], sides: F) -> Vec<usize> {
+    let mut edges = claims
+        .iter()
+        .flat_map(|claim| {
+            let (start, end) = sides(claim);
+            vec![start, end]
+        })
+        .collect::<Vec<usize>>();
+    edges.sort();
+    edges.dedup();
+    edges
+}
+
+/// The piece of the fabric between `edges` holding `at`, if any does.
+fn piece(edges: &[usize], at: usize) -> Option<usize> {
+    let i = match edges.binary_search(&at) {
+        Ok(i) => i,
+        Err(0) => return None,
+        Err(i) => i - 1,
+    };
+    if i + 1 < edges.len() {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+/// The distance from the first edge to the last.
+fn span(edges: &[usize]) -> usize {
+    edges.last().map_or(0, |last| last - edges[0])
+}
+
+impl Fabric {
+    pub fn new(claims: &[Claim]) -> Fabric {
+        let columns = edges(claims, |claim| (usize::from(claim.x), claim.right()));
+        let rows = edges(claims, |claim| (usize::from(claim.y), claim.bottom()));
+        let width = columns.len().saturating_sub(1);
+        let height = rows.len().saturating_sub(1);
+        let position = |edges: &[usize], at| edges.binary_search(&at).expect("Edge of a claim");
+
+        let mut counts = Grid::new(width, height, 0u16);
+        // The first claim to cover each piece, which only matters until a second does.
+        let mut owners = Grid::new(width, height, 0);
+        let mut overlapped = vec![false; claims.len()];
+        let mut contested = 0;
+
+        for (index, claim) in claims.iter().enumerate() {
+            let left = position(&columns, usize::from(claim.x));
+            let right = position(&columns, claim.right());
+            let top = position(&rows, usize::from(claim.y));
+            let bottom = position(&rows, claim.bottom());
+            for y in top..bottom {
+                for x in left..right {
+                    let point = Point(x, y);
+                    counts[point] = counts[point].saturating_add(1);
+                    match counts[point] {
+                        1 => owners[point] = index,
+                        2 => {
+                            contested += (columns[x + 1] - columns[x]) * (rows[y + 1] - rows[y]);
+                            overlapped[owners[point]] = true;
+                            overlapped[index] = true;
+                        }
+                        _ => overlapped[index] = true,
+                    }
+                }
+            }
+        }
+
+        let intact = claims
+            .iter()
+            .zip(overlapped)
+            .filter(|&(_, overlapped)| !overlapped)
+            .map(|(claim, _)| claim.id)
+            .collect();
+
+        Fabric {
+            columns,
+            rows,
+            counts,
+            contested,
+            intact,
+        }
+    }
+
+    /// The number of square inches covered by two or more claims.
+    pub fn contested_area(&self) -> usize {
+        self.contested
+    }
+
+    /// How many claims cover the square inch at `point`.
+    pub fn count(&self, Point(x, y): Point<usize>) -> u16 {
+        match (piece(&self.columns, x), piece(&self.rows, y)) {
+            (Some(x), Some(y)) => self.counts[Point(x, y)],
+            _ => 0,
+        }
+    }
+
+    /// The IDs of the claims that overlap no other, in the order they were given.
+    pub fn intact(&self) -> &[u16] {
+        &self.intact
+    }
+
+    /// The top-left corner of the smallest rectangle holding every claim.
+    pub fn origin(&self) -> Point<usize> {
+        Point(
+            self.columns.first().cloned().unwrap_or(0),
+            self.rows.first().cloned().unwrap_or(0),
+        )
+    }
+
+    /// The width and height of the smallest rectangle holding every claim.
+    pub fn size(&self) -> (usize, usize) {
+        (span(&self.columns), span(&self.rows))
+    }
+
+    /// A graymap of the fabric from its top-left claim, brighter where more claims overlap and
+    /// white where the most do. It has a pixel for every square inch of `size`.
+    pub fn heatmap(&self) -> Grid<u8> {
+        let most = self.counts.iter().map(|(_, &count)| count).max().unwrap_or(0);
+        let (width, height) = self.size();
+        let Point(left, top) = self.origin();
+        let mut heatmap = Grid::new(width, height, 0);
+        for (Point(x, y), &count) in self.counts.iter() {
+            let level = (usize::from(count) * 255 / usize::from(most.max(1))) as u8;
+            for row in self.rows[y]..self.rows[y + 1] {
+                for column in self.columns[x]..self.columns[x + 1] {
+                    heatmap[Point(column - left, row - top)] = level;
+                }
+            }
+        }
+
+        heatmap
+    }
+}
+
+/// Finds the claim with the ID `id`.
+pub fn find(claims: &[Claim], id: u16) -> aoc_common::Result<&Claim> {
+    claims
+        .iter()
+        .find(|claim| claim.id == id)
+        .ok_or_else(|| format!("no claim #{}", id).into())
+}
+
+/// The IDs of the claims that overlap the claim with the ID `id`, in the order they were given.
+pub fn overlapping(claims: &[Claim], id: u16) -> aoc_common::Result<Vec<u16>> {
+    let claim = find(claims, id)?;
+    Ok(claims
+        .iter()
+        .filter(|&other| !std::ptr::eq(other, claim) && claim.intersection(other).is_some())
+        .map(|other| other.id)
+        .collect())
+}
+
+/// The number of square inches covered by both of the claims with the IDs `a` and `b`.
+pub fn overlap_area(claims: &[Claim], a: u16, b: u16) -> aoc_common::Result<usize> {
+    Ok(find(claims, a)?.overlap_area(find(claims, b)?))
+}
+
+/// The IDs of the claims covering the square inch at `point`, in the order they were given.
+pub fn covering(claims: &[Claim], point: Point<usize>) -> Vec<u16> {
+    claims
+        .iter()
+        .filter(|claim| claim.contains(point))
+        .map(|claim| claim.id)
+        .collect()
+}
+
+/// Every claim's ID with the sorted IDs of the claims it overlaps, including claims that
+/// overlap none.
+///
+/// Sweeps across the fabric from left to right, so each claim is only compared with the claims
+/// that span its left edge rather than with every other claim.
+pub fn overlap_graph(claims: &[Claim]) -> BTreeMap<u16, Vec<u16>> {
+    let mut graph = claims
+        .iter()
+        .map(|claim| (claim.id, vec![]))
+        .collect::<BTreeMap<u16, Vec<u16>>>();
+
+    let mut order = claims.iter().collect::<Vec<&Claim>>();
+    order.sort_by_key(|claim| claim.x);
+    let mut active: Vec<&Claim> = vec![];
+    for claim in order {
+        active.retain(|other| other.right() > usize::from(claim.x));
+        for other in &active {
+            if claim.intersection(other).is_some() {
+                graph.get_mut(&claim.id).unwrap().push(other.id);
+                graph.get_mut(&other.id).unwrap().push(claim.id);
+            }
+        }
+        active.push(claim);
+    }
+
+    for neighbors in graph.values_mut() {
+        neighbors.sort();
+    }
+
+    graph
+}
+
+const OUTLINE: Rgb = [0, 200, 255];
+
+/// The most square inches `draw` will draw, 48 MiB of pixels and plenty for a 1000 inch
+/// square sheet.
+const MAX_DRAWN_AREA: usize = 1 << 24;
+
+/// Draws the fabric's heatmap with the intact claims outlined, or fails if the claims are
+/// spread over more than `MAX_DRAWN_AREA`.
+pub fn draw(claims: &[Claim]) -> aoc_common::Result<Grid<Rgb>> {
+    let fabric = Fabric::new(claims);
+    let (width, height) = fabric.size();
+    if width.saturating_mul(height) > MAX_DRAWN_AREA {
+        return Err(format!(
+            "the claims span {}x{} square inches, more than the {} that can be drawn",
+            width, height, MAX_DRAWN_AREA
+        )
+        .into());
+    }
+
+    let heatmap = fabric.heatmap();
+    let mut image = Grid::new(heatmap.width(), heatmap.height(), [0, 0, 0]);
+    for (point, &level) in heatmap.iter() {
+        image[point] = [level, level, level];
+    }
+
+    let intact = fabric.intact().iter().collect::<HashSet<&u16>>();
+    let Point(left, top) = fabric.origin();
+    for claim in claims.iter().filter(|claim| intact.contains(&claim.id)) {
+        if let Some(bounds) = claim.bounds() {
+            for point @ Point(x, y) in bounds.points() {
+                if bounds.is_edge(point) {
+                    image[Point(x - left, y - top)] = OUTLINE;
+                }
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+pub fn part1(input: &[Claim]) -> usize {
+    Fabric::new(input).contested_area()
+}
+
+/// The IDs of every claim that overlaps no other, in increasing order.
+pub fn part2(input: &[Claim]) -> Vec<u16> {
+    let mut intact = Fabric::new(input).intact().to_vec();
+    intact.sort();
+    intact
+}
+
+/// The ID of the one claim that overlaps no other, which the puzzle promises there is.
+pub fn intact_claim(input: &[Claim]) -> aoc_common::Result<u16> {
+    match part2(input)[..] {
+        [id] => Ok(id),
+        [] => Err("every claim overlaps another".into()),
+        ref ids => {
+            let ids = ids
+                .iter()
+                .map(|id| format!("#{}", id))
+                .collect::<Vec<String>>();
+            Err(format!("{} claims are intact: {}", ids.len(), ids.join(" ")).into())
+        }
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Claim>;
+    type Part1 = usize;
+    type Part2 = u16;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Self::Input> {
+        parse_reader(reader)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        intact_claim(input)
+    }
+}
+
+impl Render for Day3 {
+    fn render(input: &Self::Input, frames: &mut Frames) -> aoc_common::Result<()> {
+        frames.write(&draw(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    const EXAMPLE: &str = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 4);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), vec![3]);
+        assert_eq!(intact_claim(&parse(EXAMPLE).unwrap()).unwrap(), 3);
+    }
+
+    #[test]
+    fn part2_sorts_every_intact_claim() {
+        let claims = parse("#9 @ 0,0: 1x1\n#4 @ 2,2: 1x1\n#7 @ 4,4: 1x1\n#5 @ 4,4: 1x1\n").unwrap();
+        assert_eq!(part2(&claims), vec![4, 9]);
+        assert_eq!(
+            intact_claim(&claims).unwrap_err().to_string(),
+            "2 claims are intact: #4 #9"
+        );
+        assert!(intact_claim(&parse("#1 @ 0,0: 1x1\n#2 @ 0,0: 1x1\n").unwrap()).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_duplicate_ids() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#1 @ 5,5: 2x2\n";
+        let expected = ParseError::new(3, 2, "a claim id not used before", Some("1".to_string()));
+        assert_eq!(parse(input), Err(expected.clone()));
+        let error = parse_reader(input.as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn fabric_matches_counting_every_square_inch() {
+        let claims = parse(&generate(&mut Rng::new(3), 300)).unwrap();
+        let mut counts = HashMap::new();
+        for claim in &claims {
+            for point in claim.points() {
+                *counts.entry(point).or_insert(0) += 1;
+            }
+        }
+        let intact = claims
+            .iter()
+            .filter(|claim| claim.points().all(|point| counts[&point] == 1))
+            .map(|claim| claim.id)
+            .collect::<Vec<u16>>();
+
+        let fabric = Fabric::new(&claims);
+        let contested = counts.values().filter(|&&n| n > 1).count();
+        assert_eq!(fabric.contested_area(), contested);
+        assert_eq!(fabric.intact(), &intact[..]);
+        for y in 0..1000 {
+            for x in 0..1000 {
+                let expected = counts.get(&Point(x, y)).cloned().unwrap_or(0);
+                assert_eq!(fabric.count(Point(x, y)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn fabric_handles_empty_claims() {
+        let fabric = Fabric::new(&[]);
+        assert_eq!(fabric.contested_area(), 0);
+        assert!(fabric.intact().is_empty());
+
+        let claims = parse("#1 @ 2,2: 0x0\n#2 @ 1,1: 3x3\n#3 @ 2,2: 1x1\n").unwrap();
+        let fabric = Fabric::new(&claims);
+        assert_eq!(fabric.contested_area(), 1);
+        assert_eq!(fabric.intact(), &[1]);
+    }
+
+    #[test]
+    fn fabric_handles_claims_far_apart() {
+        let claims =
+            parse("#1 @ 0,0: 2x2\n#2 @ 60000,60000: 3x3\n#3 @ 60001,60001: 5x5\n").unwrap();
+        let fabric = Fabric::new(&claims);
+        assert_eq!(fabric.contested_area(), 4);
+        assert_eq!(fabric.intact(), &[1]);
+        assert_eq!(fabric.size(), (60006, 60006));
+        assert_eq!(fabric.count(Point(1, 1)), 1);
+        assert_eq!(fabric.count(Point(2, 2)), 0);
+        assert_eq!(fabric.count(Point(60000, 60000)), 1);
+        assert_eq!(fabric.count(Point(60002, 60002)), 2);
+        assert_eq!(fabric.count(Point(60005, 60005)), 1);
+        assert_eq!(fabric.count(Point(60006, 60005)), 0);
+
+        assert_eq!(part1(&claims), 4);
+        assert_eq!(intact_claim(&claims).unwrap(), 1);
+        assert_eq!(
+            draw(&claims).unwrap_err().to_string(),
+            "the claims span 60006x60006 square inches, more than the 16777216 that can be drawn"
+        );
+    }
+
+    #[test]
+    fn heatmap_brightens_with_overlaps() {
+        let fabric = Fabric::new(&parse(EXAMPLE).unwrap());
+        let heatmap = fabric.heatmap();
+        assert_eq!((heatmap.width(), heatmap.height()), (6, 6));
+        assert_eq!(heatmap[Point(0, 0)], 0);
+        assert_eq!(heatmap[Point(0, 2)], 127);
+        assert_eq!(heatmap[Point(2, 2)], 255);
+    }
+
+    #[test]
+    fn drawing_outlines_intact_claims() {
+        let image = draw(&parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(image[Point(4, 4)], OUTLINE);
+        assert_eq!(image[Point(5, 5)], OUTLINE);
+        assert_eq!(image[Point(2, 2)], [255, 255, 255]);
+        assert_eq!(image[Point(0, 0)], [0, 0, 0]);
+    }
+
+    #[test]
+    fn claim_intersections() {
+        let claims = parse(EXAMPLE).unwrap();
+        let overlap = Bounds {
+            min: Point(3, 3),
+            max: Point(4, 4),
+        };
+        assert_eq!(claims[0].intersection(&claims[1]), Some(overlap));
+        assert_eq!(claims[1].intersection(&claims[0]), Some(overlap));
+        assert_eq!(claims[0].intersection(&claims[2]), None);
+        assert_eq!(claims[0].overlap_area(&claims[1]), 4);
+        assert_eq!(claims[0].overlap_area(&claims[0]), 16);
+        // Claims that only touch along an edge don't overlap.
+        let touching = "#4 @ 5,1: 1x1".parse::<Claim>().unwrap();
+        assert_eq!(claims[0].intersection(&touching), None);
+        assert!(claims[2].contains(Point(6, 6)));
+        assert!(!claims[2].contains(Point(7, 6)));
+    }
+
+    #[test]
+    fn query_examples() {
+        let claims = parse(EXAMPLE).unwrap();
+        assert_eq!(overlapping(&claims, 1).unwrap(), vec![2]);
+        assert!(overlapping(&claims, 3).unwrap().is_empty());
+        assert!(overlapping(&claims, 4).is_err());
+        assert_eq!(overlap_area(&claims, 1, 2).unwrap(), 4);
+        assert_eq!(overlap_area(&claims, 2, 3).unwrap(), 0);
+        assert_eq!(covering(&claims, Point(3, 4)), vec![1, 2]);
+        assert!(covering(&claims, Point(0, 0)).is_empty());
+
+        let graph = overlap_graph(&claims);
+        let expected = vec![(1, vec![2]), (2, vec![1]), (3, vec![])];
+        assert_eq!(graph, expected.into_iter().collect());
+    }
+
+    #[test]
+    fn overlap_graph_matches_comparing_every_pair() {
+        let claims = parse(&generate(&mut Rng::new(4), 300)).unwrap();
+        let graph = overlap_graph(&claims);
+        for claim in &claims {
+            assert_eq!(graph[&claim.id], overlapping(&claims, claim.id).unwrap());
+        }
+    }
+
+    #[test]
+    fn parse_claim() {
+        assert_eq!(
+            "#123 @ 3,2: 5x4".parse(),
+            Ok(Claim {
+                id: 123,
+                x: 3,
+                y: 2,
+                width: 5,
+                height: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_claims() {
+        assert_eq!(
+            parse("#1 @ 1,3: 4x4\n#2 @ 3,1 4x4\n"),
+            Err(ParseError::new(2, 10, "`:`", Some("4x4".to_string())))
+        );
+    }
+
+    #[test]
+    fn generated_claims_leave_one_intact() {
+        let claims = parse(&generate(&mut Rng::new(1), 200)).unwrap();
+        assert_eq!(claims.len(), 200);
+        let intact = intact_claim(&claims).unwrap();
+        assert!(claims.iter().any(|claim| claim.id == intact));
+    }
+
+    fn claim() -> impl Strategy<Value = Claim> {
+        (
+            any::<u16>(),
+            any::<u16>(),
+            any::<u16>(),
+            any::<u16>(),
+            any::<u16>(),
+        )
+            .prop_map(|(id, x, y, width, height)| Claim {
+                id,
+                x,
+                y,
+                width,
+                height,
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn claim_round_trips(claim in claim()) {
+            let spaced = claim.to_string().replace(' ', "   ");
+            prop_assert_eq!(claim.to_string().parse(), Ok(claim));
+            prop_assert!(spaced.parse::<Claim>().is_ok());
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = parse(&input);
+        }
+    }
+}
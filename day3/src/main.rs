@@ -1,97 +1,78 @@
-extern crate itertools;
+extern crate aoc_common;
+extern crate day3;
 
-use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
+use aoc_common::{Point, Result, Source};
 use std::env;
-use std::fs;
-use std::num::ParseIntError;
-use std::str::FromStr;
+use std::process;
 
-#[derive(Debug, PartialEq)]
-struct Claim {
-    id: u16,
-    x: u16,
-    y: u16,
-    width: u16,
-    height: u16,
-}
-
-impl FromStr for Claim {
-    type Err = ParseIntError;
-
-    fn from_str(source: &str) -> Result<Self, Self::Err> {
-        let mut parts = source.split_whitespace();
-        let id = parts.next().unwrap().trim_start_matches('#').parse()?;
-        let _ = parts.next();
-        let mut position = parts.next().unwrap().trim_end_matches(':').split(',');
-        let x = position.next().unwrap().parse()?;
-        let y = position.next().unwrap().parse()?;
-        let mut size = parts.next().unwrap().split('x');
-        let width = size.next().unwrap().parse()?;
-        let height = size.next().unwrap().parse()?;
+const QUERY_USAGE: &str = "usage: day3 query overlapping ID [FILE|-]
+       day3 query area ID ID [FILE|-]
+       day3 query covering X,Y [FILE|-]
+       day3 query graph [FILE|-]";
 
-        Ok(Claim {
-            id,
-            x,
-            y,
-            width,
-            height,
-        })
-    }
+/// Reads a claim ID, with or without its `#`.
+fn id<I: Iterator<Item = String>>(args: &mut I) -> Result<u16> {
+    let id = args.next().ok_or(QUERY_USAGE)?;
+    id.trim_start_matches('#')
+        .parse()
+        .map_err(|_| format!("not a claim ID: {}", id).into())
 }
 
-fn part1(input: &[Claim]) {
-    let answer = input
-        .iter()
-        .fold(HashMap::new(), |mut acc, claim| {
-            (claim.x..claim.x + claim.width)
-                .cartesian_product(claim.y..claim.y + claim.height)
-                .for_each(|(i, j)| {
-                    *acc.entry((i, j)).or_insert(0) += 1;
-                });
-
-            acc
-        })
-        .values()
-        .filter(|&n| *n > 1)
-        .count();
+fn ids(ids: &[u16]) -> String {
+    ids.iter()
+        .map(|id| format!("#{}", id))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
 
-    println!("part 1: {}", answer);
+enum Query {
+    Overlapping(u16),
+    Area(u16, u16),
+    Covering(Point<usize>),
+    Graph,
 }
 
-fn part2(input: &[Claim]) {
-    let repeated = input
-        .iter()
-        .fold(HashMap::new(), |mut acc, claim| {
-            (claim.x..claim.x + claim.width)
-                .cartesian_product(claim.y..claim.y + claim.height)
-                .for_each(|(i, j)| {
-                    acc.entry((i, j)).or_insert_with(Vec::new).push(claim.id);
-                });
+/// Answers a question about the claims other than the puzzle's, printing claim IDs with a `#`.
+fn query<I: Iterator<Item = String>>(mut args: I) -> Result<()> {
+    let kind = args.next().ok_or(QUERY_USAGE)?;
+    let query = match kind.as_str() {
+        "overlapping" => Query::Overlapping(id(&mut args)?),
+        "area" => Query::Area(id(&mut args)?, id(&mut args)?),
+        "covering" => Query::Covering(args.next().ok_or(QUERY_USAGE)?.parse()?),
+        "graph" => Query::Graph,
+        _ => return Err(QUERY_USAGE.into()),
+    };
 
-            acc
-        })
-        .values()
-        .filter(|claims| claims.len() > 1)
-        .flatten()
-        .cloned()
-        .collect::<HashSet<u16>>();
+    let file = args.next();
+    if let Some(arg) = args.next() {
+        return Err(format!("unexpected argument: {}", arg).into());
+    }
+    let claims = day3::parse_reader(Source::from_arg(file).open()?)?;
 
-    let all_claims: HashSet<u16> = input.iter().map(|claim| claim.id).collect();
-    let answer = all_claims.difference(&repeated).nth(0).unwrap();
+    match query {
+        Query::Overlapping(id) => println!("{}", ids(&day3::overlapping(&claims, id)?)),
+        Query::Area(a, b) => println!("{}", day3::overlap_area(&claims, a, b)?),
+        Query::Covering(point) => println!("{}", ids(&day3::covering(&claims, point))),
+        Query::Graph => {
+            for (id, neighbors) in day3::overlap_graph(&claims) {
+                let line = format!("#{}: {}", id, ids(&neighbors));
+                println!("{}", line.trim_end());
+            }
+        }
+    }
 
-    println!("part 2: {}", answer);
+    Ok(())
 }
 
 fn main() {
-    let filename = env::args().nth(1).expect("No file provided");
-    let input = fs::read_to_string(filename)
-        .expect("File to read")
-        .lines()
-        .map(|line| line.parse())
-        .collect::<Result<Vec<Claim>, _>>()
-        .expect("Unable to parse input");
+    let mut args = env::args().skip(1);
+    if args.next().as_deref() != Some("query") {
+        aoc_common::main::<day3::Day3>();
+        return;
+    }
 
-    part1(&input);
-    part2(&input);
+    if let Err(e) = query(args) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,71 @@
+use aoc_common::Rng;
+
+const DAYS_IN_MONTH: [i64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// The date `ordinal` days after 1518-01-01, ignoring leap years as 1518 wasn't one.
+fn date(ordinal: i64) -> String {
+    let (year, mut day) = (1518 + ordinal / 365, ordinal % 365);
+    let mut month = 0;
+    while day >= DAYS_IN_MONTH[month] {
+        day -= DAYS_IN_MONTH[month];
+        month += 1;
+    }
+
+    format!("{}-{:02}-{:02}", year, month + 1, day + 1)
+}
+
+/// A shuffled guard log covering `size` nights (at least one), with about one guard for every
+/// ten nights.
+///
+/// Some shifts start shortly before midnight on the previous day, as in the puzzle, but guards
+/// only ever sleep during the midnight hour.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut guards: Vec<i64> = vec![];
+    while guards.len() < 1 + size / 10 {
+        let id = rng.range(10, 3500);
+        if !guards.contains(&id) {
+            guards.push(id);
+        }
+    }
+
+    let mut lines = vec![];
+    for night in 1..=size as i64 {
+        let guard = rng.choose(&guards);
+        let today = date(night);
+        let mut minute = if rng.chance(30) {
+            let start = rng.range(45, 60);
+            lines.push(format!(
+                "[{} 23:{:02}] Guard #{} begins shift",
+                date(night - 1),
+                start,
+                guard
+            ));
+            0
+        } else {
+            let start = rng.range(0, 4);
+            lines.push(format!(
+                "[{} 00:{:02}] Guard #{} begins shift",
+                today, start, guard
+            ));
+            start + 1
+        };
+
+        // The first night always has a nap, so there is always a sleepiest guard.
+        if night > 1 && rng.chance(15) {
+            continue;
+        }
+
+        minute += rng.range(0, 20);
+        while minute < 58 {
+            let wake = (minute + rng.range(1, 20)).min(59);
+            lines.push(format!("[{} 00:{:02}] falls asleep", today, minute));
+            lines.push(format!("[{} 00:{:02}] wakes up", today, wake));
+            minute = wake + rng.range(1, 15);
+        }
+    }
+
+    // The log is unordered in the puzzle.
+    rng.shuffle(&mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
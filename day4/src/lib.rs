@@ -0,0 +1,613 @@
+extern crate aoc_common;
+#[cfg(test)]
+extern crate proptest;
+
+use aoc_common::{ParseError, Scanner, Solution};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::str::FromStr;
+
+mod generate;
+
+pub use generate::generate;
+
+pub type Schedule = HashMap<u16, HashMap<u8, usize>>;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Action {
+    StartShift(u16),
+    FallAsleep,
+    WakeUp,
+}
+
+/// Minutes in a day.
+const DAY: i64 = 24 * 60;
+
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days from 0000-03-01 to the given date in the Gregorian calendar.
+///
+/// Counting years from March puts the leap day at the end of the year, which keeps the
+/// arithmetic to whole 400 year eras, 4 year cycles and five month runs of 153 days.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era
+}
+
+/// The date `days` days after 0000-03-01, undoing `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// A date and time to the minute, which is always a real moment in the Gregorian calendar.
+///
+/// Timestamps order from earliest to latest.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+}
+
+impl Timestamp {
+    /// The timestamp for the given date and time, or `None` if there is no such moment.
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8) -> Option<Timestamp> {
+        let valid = (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+            && hour < 24
+            && minute < 60;
+        if valid {
+            Some(Timestamp {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The timestamp `minutes` minutes after 0000-03-01 00:00, or `None` if its year doesn't
+    /// fit in a `u16`.
+    pub fn from_minutes(minutes: i64) -> Option<Timestamp> {
+        let (year, month, day) = civil_from_days(minutes.div_euclid(DAY));
+        let time = minutes.rem_euclid(DAY);
+        if year < 0 || year > i64::from(u16::MAX) {
+            return None;
+        }
+
+        Timestamp::new(
+            year as u16,
+            month as u8,
+            day as u8,
+            (time / 60) as u8,
+            (time % 60) as u8,
+        )
+    }
+
+    /// The number of minutes since 0000-03-01 00:00.
+    pub fn minutes(self) -> i64 {
+        let days = days_from_civil(self.year.into(), self.month.into(), self.day.into());
+        days * DAY + i64::from(self.hour) * 60 + i64::from(self.minute)
+    }
+
+    /// The timestamp `minutes` minutes later, or earlier if `minutes` is negative.
+    pub fn add_minutes(self, minutes: i64) -> Option<Timestamp> {
+        Timestamp::from_minutes(self.minutes() + minutes)
+    }
+
+    /// The number of minutes from `earlier` until this timestamp.
+    pub fn minutes_since(self, earlier: Timestamp) -> i64 {
+        self.minutes() - earlier.minutes()
+    }
+
+    /// The start of the midnight hour that a shift starting now covers, counted like `minutes`:
+    /// today's midnight during the midnight hour itself, and the coming midnight otherwise.
+    fn night(self) -> i64 {
+        let today = self.minutes() - i64::from(self.hour) * 60 - i64::from(self.minute);
+        if self.hour == 0 {
+            today
+        } else {
+            today + DAY
+        }
+    }
+
+    /// Reads a timestamp written as `1518-11-01 00:25`.
+    fn scan(scanner: &mut Scanner) -> Result<Timestamp, ParseError> {
+        let year = scanner.integer("year")?;
+        scanner.expect("-")?;
+        let month = in_range(scanner, "month", 1, 12)?;
+        scanner.expect("-")?;
+        let column = scanner.column();
+        let day = scanner.integer::<u8>("day")?;
+        if day < 1 || day > days_in_month(year, month) {
+            let expected = format!("day from 1 to {}", days_in_month(year, month));
+            return Err(ParseError::new(1, column, expected, Some(day.to_string())));
+        }
+
+        let hour = in_range(scanner, "hour", 0, 23)?;
+        scanner.expect(":")?;
+        let minute = in_range(scanner, "minute", 0, 59)?;
+
+        Ok(Timestamp {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        })
+    }
+}
+
+/// Reads a number that has to be between `low` and `high`, inclusive.
+fn in_range(scanner: &mut Scanner, what: &str, low: u8, high: u8) -> Result<u8, ParseError> {
+    let column = scanner.column();
+    let value = scanner.integer::<u8>(what)?;
+    if value < low || value > high {
+        let expected = format!("{} from {} to {}", what, low, high);
+        return Err(ParseError::new(1, column, expected, Some(value.to_string())));
+    }
+
+    Ok(value)
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            formatter,
+            "{}-{:02}-{:02} {:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Event {
+    time: Timestamp,
+    action: Action,
+}
+
+impl FromStr for Event {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(source);
+
+        scanner.expect("[")?;
+        let time = Timestamp::scan(&mut scanner)?;
+        scanner.expect("]")?;
+
+        let action = if scanner.accept("Guard") {
+            scanner.expect("#")?;
+            let id = scanner.integer("guard id")?;
+            scanner.expect("begins")?;
+            scanner.expect("shift")?;
+            Action::StartShift(id)
+        } else if scanner.accept("falls") {
+            scanner.expect("asleep")?;
+            Action::FallAsleep
+        } else if scanner.accept("wakes") {
+            scanner.expect("up")?;
+            Action::WakeUp
+        } else {
+            return Err(scanner.error("`Guard`, `falls` or `wakes`"));
+        };
+        scanner.end()?;
+
+        Ok(Event { time, action })
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(formatter, "[{}] ", self.time)?;
+
+        match self.action {
+            Action::StartShift(guard) => write!(formatter, "Guard #{} begins shift", guard),
+            Action::FallAsleep => write!(formatter, "falls asleep"),
+            Action::WakeUp => write!(formatter, "wakes up"),
+        }
+    }
+}
+
+/// Replays the events in order, counting the minutes each guard sleeps during the midnight
+/// hour of their shift. Each event comes with its line number, for pointing at a nap logged
+/// before any guard's shift has begun.
+///
+/// A shift that starts before midnight is for the midnight hour after it, and only sleep
+/// between 00:00 and 00:59 of that hour counts, even when a nap starts before or ends after it.
+fn schedule(mut events: Vec<(usize, Event)>) -> Result<Schedule, ParseError> {
+    events.sort_by_key(|&(_, event)| event.time);
+
+    let mut schedule = HashMap::new();
+    // The guard on shift and the start of the midnight hour they watch.
+    let mut shift = None;
+    let mut asleep = None;
+    for &(line, event) in &events {
+        match (event.action, shift) {
+            (Action::StartShift(id), _) => {
+                shift = Some((id, event.time.night()));
+                asleep = None;
+            }
+            (_, None) => {
+                return Err(ParseError::new(
+                    line,
+                    1,
+                    "a guard to begin a shift first",
+                    Some(event.to_string()),
+                ));
+            }
+            (Action::FallAsleep, _) => asleep = Some(event.time),
+            (Action::WakeUp, Some((guard, midnight))) => {
+                if let Some(start) = asleep.take() {
+                    let from = start.minutes().max(midnight);
+                    let to = event.time.minutes().min(midnight + 60);
+                    for minute in from..to {
+                        *schedule
+                            .entry(guard)
+                            .or_insert_with(HashMap::new)
+                            .entry((minute - midnight) as u8)
+                            .or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(schedule)
+}
+
+/// Parses line `i`, counting from zero, as an event tagged with its line number, or `None` if
+/// the line is blank.
+fn numbered_event(i: usize, line: &str) -> Option<Result<(usize, Event), ParseError>> {
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    Some(
+        line.parse()
+            .map(|event| (i + 1, event))
+            .map_err(|e: ParseError| e.on_line(i + 1)),
+    )
+}
+
+pub fn parse(input: &str) -> Result<Schedule, ParseError> {
+    let events = input
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| numbered_event(i, line))
+        .collect::<Result<Vec<(usize, Event)>, ParseError>>()?;
+    schedule(events)
+}
+
+pub fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Schedule> {
+    let mut events = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        if let Some(event) = numbered_event(i, &line?) {
+            events.push(event?);
+        }
+    }
+
+    Ok(schedule(events)?)
+}
+
+/// The error when nobody sleeps, as happens when every nap is before midnight.
+const NO_SLEEP: &str = "no guard slept during the midnight hour";
+
+/// The minute a guard was asleep most often, preferring the earliest on a tie.
+fn sleepiest_minute(counts: &HashMap<u8, usize>) -> u8 {
+    let (&minute, _) = counts
+        .iter()
+        .max_by_key(|&(&minute, &count)| (count, Reverse(minute)))
+        .unwrap();
+    minute
+}
+
+pub fn part1(schedule: &Schedule) -> aoc_common::Result<u32> {
+    let (&guard, counts) = schedule
+        .iter()
+        .max_by_key(|&(&guard, counts)| (counts.values().sum::<usize>(), Reverse(guard)))
+        .ok_or(NO_SLEEP)?;
+
+    Ok(u32::from(guard) * u32::from(sleepiest_minute(counts)))
+}
+
+pub fn part2(schedule: &Schedule) -> aoc_common::Result<u32> {
+    let (&guard, counts) = schedule
+        .iter()
+        .max_by_key(|&(&guard, counts)| (counts.values().max(), Reverse(guard)))
+        .ok_or(NO_SLEEP)?;
+
+    Ok(u32::from(guard) * u32::from(sleepiest_minute(counts)))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Schedule;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Self::Input> {
+        parse_reader(reader)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+    use proptest::prelude::*;
+
+    // The example records, shuffled to check that events are sorted before they are replayed.
+    const EXAMPLE: &str = "\
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:55] wakes up
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()).unwrap(), 240);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()).unwrap(), 4455);
+    }
+
+    #[test]
+    fn parse_reader_matches_parse() {
+        assert_eq!(
+            parse_reader(EXAMPLE.as_bytes()).unwrap(),
+            parse(EXAMPLE).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_actions() {
+        assert_eq!(
+            parse("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] snores\n"),
+            Err(ParseError::new(
+                2,
+                20,
+                "`Guard`, `falls` or `wakes`",
+                Some("snores".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_naps_before_any_shift() {
+        let input = "\n[1518-11-01 00:25] wakes up\n[1518-11-01 00:05] falls asleep\n";
+        let expected = ParseError::new(
+            3,
+            1,
+            "a guard to begin a shift first",
+            Some("[1518-11-01 00:05] falls asleep".to_string()),
+        );
+        assert_eq!(parse(input), Err(expected.clone()));
+        let error = parse_reader(input.as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), expected.to_string());
+
+        let input = "[1518-11-01 00:25] wakes up\n[1518-11-01 00:30] Guard #10 begins shift\n";
+        assert_eq!(parse(input).unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn timestamps_are_real_moments() {
+        assert!(Timestamp::new(1518, 2, 28, 23, 59).is_some());
+        assert!(Timestamp::new(1518, 2, 29, 0, 0).is_none());
+        assert!(Timestamp::new(1600, 2, 29, 0, 0).is_some());
+        assert!(Timestamp::new(1700, 2, 29, 0, 0).is_none());
+        assert!(Timestamp::new(1518, 4, 31, 0, 0).is_none());
+        assert!(Timestamp::new(1518, 13, 1, 0, 0).is_none());
+        assert!(Timestamp::new(1518, 0, 1, 0, 0).is_none());
+        assert!(Timestamp::new(1518, 1, 0, 0, 0).is_none());
+        assert!(Timestamp::new(1518, 1, 1, 24, 0).is_none());
+        assert!(Timestamp::new(1518, 1, 1, 0, 60).is_none());
+    }
+
+    #[test]
+    fn timestamp_arithmetic_crosses_days_months_and_years() {
+        let time = Timestamp::new(1518, 12, 31, 23, 58).unwrap();
+        let later = time.add_minutes(3).unwrap();
+        assert_eq!(later, Timestamp::new(1519, 1, 1, 0, 1).unwrap());
+        assert_eq!(later.minutes_since(time), 3);
+        assert!(time < later);
+        assert_eq!(later.to_string(), "1519-01-01 00:01");
+
+        let time = Timestamp::new(1600, 2, 28, 12, 0).unwrap();
+        assert_eq!(time.add_minutes(24 * 60).unwrap().to_string(), "1600-02-29 12:00");
+        assert_eq!(Timestamp::new(0, 1, 1, 0, 0).unwrap().add_minutes(-1), None);
+    }
+
+    #[test]
+    fn parse_rejects_impossible_timestamps() {
+        assert_eq!(
+            parse("[1518-13-01 00:00] Guard #10 begins shift\n"),
+            Err(ParseError::new(
+                1,
+                7,
+                "month from 1 to 12",
+                Some("13".to_string())
+            ))
+        );
+        assert_eq!(
+            parse("[1518-02-29 00:00] falls asleep\n"),
+            Err(ParseError::new(
+                1,
+                10,
+                "day from 1 to 28",
+                Some("29".to_string())
+            ))
+        );
+        assert_eq!(
+            parse("[1518-02-01 00:60] falls asleep\n"),
+            Err(ParseError::new(
+                1,
+                16,
+                "minute from 0 to 59",
+                Some("60".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn shifts_count_only_the_midnight_hour() {
+        let log = "\
+[1518-03-31 23:50] Guard #7 begins shift
+[1518-03-31 23:55] falls asleep
+[1518-04-01 00:02] wakes up
+[1518-04-01 00:58] falls asleep
+[1518-04-01 01:10] wakes up
+";
+        let schedule = parse(log).unwrap();
+        let minutes = &schedule[&7];
+        let mut asleep = minutes.keys().cloned().collect::<Vec<u8>>();
+        asleep.sort();
+        assert_eq!(asleep, vec![0, 1, 58, 59]);
+        assert!(minutes.values().all(|&count| count == 1));
+    }
+
+    #[test]
+    fn generated_log_parses() {
+        let schedule = parse(&generate(&mut Rng::new(1), 50)).unwrap();
+        assert!(schedule.len() <= 6);
+        assert_eq!(part1(&schedule).unwrap(), 2014);
+        assert_eq!(part2(&schedule).unwrap(), 2014);
+    }
+
+    #[test]
+    fn parts_reject_logs_without_midnight_sleep() {
+        let log = "\
+[1518-03-31 23:50] Guard #7 begins shift
+[1518-03-31 23:52] falls asleep
+[1518-03-31 23:55] wakes up
+";
+        let schedule = parse(log).unwrap();
+        assert!(schedule.is_empty());
+        assert_eq!(part1(&schedule).unwrap_err().to_string(), NO_SLEEP);
+        assert_eq!(part2(&schedule).unwrap_err().to_string(), NO_SLEEP);
+    }
+
+    #[test]
+    fn ties_go_to_the_lowest_guard_and_earliest_minute() {
+        let log = "\
+[1518-04-01 00:00] Guard #20 begins shift
+[1518-04-01 00:10] falls asleep
+[1518-04-01 00:12] wakes up
+[1518-04-02 00:00] Guard #10 begins shift
+[1518-04-02 00:30] falls asleep
+[1518-04-02 00:32] wakes up
+";
+        let schedule = parse(log).unwrap();
+        assert_eq!(part1(&schedule).unwrap(), 300);
+        assert_eq!(part2(&schedule).unwrap(), 300);
+    }
+
+    fn timestamp() -> impl Strategy<Value = Timestamp> {
+        (any::<u16>(), 1u8..=12)
+            .prop_flat_map(|(year, month)| {
+                let day = 1..=days_in_month(year, month);
+                (Just(year), Just(month), day, 0u8..24, 0u8..60)
+            })
+            .prop_map(|(year, month, day, hour, minute)| {
+                Timestamp::new(year, month, day, hour, minute).unwrap()
+            })
+    }
+
+    fn event() -> impl Strategy<Value = Event> {
+        let action = prop_oneof![
+            any::<u16>().prop_map(Action::StartShift),
+            Just(Action::FallAsleep),
+            Just(Action::WakeUp),
+        ];
+
+        (timestamp(), action).prop_map(|(time, action)| Event { time, action })
+    }
+
+    proptest! {
+        #[test]
+        fn event_round_trips(event in event()) {
+            prop_assert_eq!(event.to_string().parse(), Ok(event));
+        }
+
+        #[test]
+        fn minutes_round_trip(time in timestamp(), offset in -1_000_000i64..1_000_000) {
+            prop_assert_eq!(Timestamp::from_minutes(time.minutes()), Some(time));
+            if let Some(later) = time.add_minutes(offset) {
+                prop_assert_eq!(later.minutes_since(time), offset);
+                prop_assert_eq!(later.cmp(&time), offset.cmp(&0));
+            }
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = parse(&input);
+        }
+    }
+}
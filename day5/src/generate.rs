@@ -0,0 +1,32 @@
+use aoc_common::Rng;
+
+/// A polymer of `size` units, where about a quarter of the units react with the one before.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut polymer = String::with_capacity(size + 1);
+    let mut previous: Option<char> = None;
+    for _ in 0..size {
+        let unit = match previous {
+            Some(unit) if rng.chance(25) => {
+                if unit.is_ascii_lowercase() {
+                    unit.to_ascii_uppercase()
+                } else {
+                    unit.to_ascii_lowercase()
+                }
+            }
+            _ => {
+                let unit = (b'a' + rng.range(0, 26) as u8) as char;
+                if rng.chance(50) {
+                    unit.to_ascii_uppercase()
+                } else {
+                    unit
+                }
+            }
+        };
+
+        polymer.push(unit);
+        previous = Some(unit);
+    }
+
+    polymer.push('\n');
+    polymer
+}
@@ -0,0 +1,136 @@
+extern crate aoc_common;
+
+use aoc_common::{ParseError, Solution};
+use std::collections::VecDeque;
+
+mod generate;
+
+pub use generate::generate;
+
+trait PolymerUnit {
+    fn is_reacting(&self, other: Self) -> bool;
+}
+
+impl PolymerUnit for char {
+    fn is_reacting(&self, other: Self) -> bool {
+        if self.is_ascii_uppercase() {
+            self.to_ascii_lowercase() == other
+        } else {
+            self.to_ascii_uppercase() == other
+        }
+    }
+}
+
+fn fully_react(mut input: VecDeque<char>) -> usize {
+    'outer: loop {
+        for i in 0..input.len() {
+            let current = input[i];
+            if let Some(&next) = input.get(i + 1) {
+                if current.is_reacting(next) {
+                    let _ = input.drain(i..=i + 1);
+                    continue 'outer;
+                }
+            }
+        }
+
+        break;
+    }
+
+    input.len()
+}
+
+pub fn parse(input: &str) -> Result<VecDeque<char>, ParseError> {
+    let mut polymer = VecDeque::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, unit) in line.chars().enumerate() {
+            if unit.is_ascii_alphabetic() {
+                polymer.push_back(unit);
+            } else if !unit.is_whitespace() {
+                let found = Some(unit.to_string());
+                return Err(ParseError::new(y + 1, x + 1, "polymer unit", found));
+            }
+        }
+    }
+
+    Ok(polymer)
+}
+
+pub fn part1(input: &VecDeque<char>) -> usize {
+    fully_react(input.clone())
+}
+
+pub fn part2(input: &VecDeque<char>) -> usize {
+    let units = (97u8..=122).map(|n| n as char).collect::<Vec<char>>();
+    aoc_common::par_map(units, |c| {
+        let polymer = input
+            .iter()
+            .filter(|&u| *u != c && u.to_ascii_lowercase() != c)
+            .cloned()
+            .collect::<VecDeque<char>>();
+        fully_react(polymer)
+    })
+    .into_iter()
+    .min()
+    .unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = VecDeque<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+
+    fn polymer(input: &str) -> VecDeque<char> {
+        parse(input).unwrap()
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&polymer("aA")), 0);
+        assert_eq!(part1(&polymer("abBA")), 0);
+        assert_eq!(part1(&polymer("abAB")), 4);
+        assert_eq!(part1(&polymer("aabAAB")), 6);
+        assert_eq!(part1(&polymer("dabAcCaCBAcCcaDA")), 10);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&polymer("dabAcCaCBAcCcaDA")), 4);
+    }
+
+    #[test]
+    fn parse_rejects_non_letters() {
+        assert_eq!(
+            parse("dabA\ncC1a\n"),
+            Err(ParseError::new(2, 3, "polymer unit", Some("1".to_string())))
+        );
+    }
+
+    #[test]
+    fn generated_polymer_parses() {
+        let input = parse(&generate(&mut Rng::new(1), 500)).unwrap();
+        assert_eq!(input.len(), 500);
+        assert!(part1(&input) < 500);
+    }
+}
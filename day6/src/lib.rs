@@ -0,0 +1,133 @@
+use aoc_common::{Bounds, ParseError, Point, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+pub fn parse(input: &str) -> Result<Vec<Point<isize>>, ParseError> {
+    aoc_common::parse_lines(input)
+}
+
+pub fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Vec<Point<isize>>> {
+    aoc_common::read_lines(reader)
+}
+
+pub fn part1(input: &[Point<isize>]) -> usize {
+    let bounds = Bounds::of(input.iter().cloned()).unwrap();
+
+    let points = bounds.points().collect::<Vec<Point<isize>>>();
+    let grid = aoc_common::par_map(points, |point| {
+        let closest = input
+            .iter()
+            .cloned()
+            .map(|p| (p, point.manhattan_distance(p)))
+            .sorted_by_key(|&(_, d)| d);
+
+        let elements = closest.as_slice();
+        match (elements[0], elements[1]) {
+            ((_, d1), (_, d2)) if d1 == d2 => (point, None),
+            ((p, _), _) => (point, Some(p)),
+        }
+    })
+    .into_iter()
+    .collect::<HashMap<Point<isize>, Option<Point<isize>>>>();
+
+    let edge_points = grid
+        .iter()
+        .filter(|&(&point, _)| bounds.is_edge(point))
+        .filter_map(|(_, &point)| point)
+        .collect::<HashSet<Point<isize>>>();
+
+    let (_, answer) = grid
+        .values()
+        .filter_map(|&point| point)
+        .filter(|point| !edge_points.contains(point))
+        .fold(HashMap::new(), |mut acc, point| {
+            *acc.entry(point).or_insert(0) += 1;
+            acc
+        })
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .unwrap();
+
+    answer
+}
+
+pub fn part2(input: &[Point<isize>]) -> usize {
+    safe_region(input, 10000)
+}
+
+/// How many points have a total distance to every coordinate of less than `limit`.
+pub fn safe_region(input: &[Point<isize>], limit: isize) -> usize {
+    let bounds = Bounds::of(input.iter().cloned()).unwrap();
+
+    let points = bounds.points().collect::<Vec<Point<isize>>>();
+    aoc_common::par_map(points, |point| {
+        input
+            .iter()
+            .map(|&p| point.manhattan_distance(p))
+            .sum::<isize>()
+            < limit
+    })
+    .into_iter()
+    .filter(|&safe| safe)
+    .count()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Point<isize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> aoc_common::Result<Self::Input> {
+        parse_reader(reader)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 17);
+    }
+
+    #[test]
+    fn safe_region_example() {
+        assert_eq!(safe_region(&parse(EXAMPLE).unwrap(), 32), 16);
+    }
+
+    #[test]
+    fn parse_reader_matches_parse() {
+        assert_eq!(
+            parse_reader(EXAMPLE.as_bytes()).unwrap(),
+            parse(EXAMPLE).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_coordinates() {
+        assert_eq!(
+            parse("1, 1\n1; 6\n"),
+            Err(ParseError::new(2, 2, "`,`", Some(";".to_string())))
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_common::Rng;
+
+/// Instructions for `size` steps (between two and 26) forming a single dependency DAG.
+///
+/// Steps get letters in a random order; each step after the first waits on at least one
+/// earlier step.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut steps = (b'A'..=b'Z').map(char::from).collect::<Vec<char>>();
+    rng.shuffle(&mut steps);
+    steps.truncate(size.clamp(2, 26));
+
+    let mut lines = vec![];
+    for (j, &step) in steps.iter().enumerate().skip(1) {
+        let mut parents = vec![steps[rng.index(j)]];
+        for _ in 0..rng.range(0, 3) {
+            let parent = steps[rng.index(j)];
+            if !parents.contains(&parent) {
+                parents.push(parent);
+            }
+        }
+
+        for parent in parents {
+            lines.push(format!(
+                "Step {} must be finished before step {} can begin.",
+                parent, step
+            ));
+        }
+    }
+
+    rng.shuffle(&mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
@@ -0,0 +1,239 @@
+use aoc_common::{ParseError, Scanner, Solution};
+use std::collections::{HashMap, HashSet};
+
+mod generate;
+
+pub use crate::generate::generate;
+
+#[derive(Clone)]
+struct Work {
+    step: char,
+    completion_time: usize,
+}
+
+pub struct Instructions {
+    step_blocks: HashMap<char, Vec<char>>,
+    step_blocked_by: HashMap<char, Vec<char>>,
+    ready: HashSet<char>,
+}
+
+fn completion_time(step: char, base: usize) -> usize {
+    (step as usize) - 64 + base
+}
+
+fn parse_step_name(scanner: &mut Scanner) -> Result<char, ParseError> {
+    match scanner.peek() {
+        Some(step) if step.is_ascii_uppercase() => scanner.character("step name"),
+        _ => Err(scanner.error("step name")),
+    }
+}
+
+fn parse_step(source: &str) -> Result<(char, char), ParseError> {
+    let mut scanner = Scanner::new(source);
+    scanner.expect("Step")?;
+    let id = parse_step_name(&mut scanner)?;
+    for word in &["must", "be", "finished", "before", "step"] {
+        scanner.expect(word)?;
+    }
+    let block = parse_step_name(&mut scanner)?;
+    scanner.expect("can")?;
+    scanner.expect("begin.")?;
+    scanner.end()?;
+
+    Ok((id, block))
+}
+
+pub fn parse(input: &str) -> Result<Instructions, ParseError> {
+    let steps = aoc_common::parse_lines_with(input, parse_step)?;
+
+    let mut step_blocks = HashMap::new();
+    let mut step_blocked_by = HashMap::new();
+
+    for &(step, block) in &steps {
+        step_blocks.entry(step).or_insert_with(Vec::new).push(block);
+        step_blocked_by
+            .entry(block)
+            .or_insert_with(Vec::new)
+            .push(step);
+    }
+
+    let ready = steps
+        .iter()
+        .filter(|(step, _)| !step_blocked_by.contains_key(step))
+        .map(|&(step, _)| step)
+        .collect::<HashSet<char>>();
+
+    Ok(Instructions {
+        step_blocks,
+        step_blocked_by,
+        ready,
+    })
+}
+
+pub fn part1(
+    Instructions {
+        step_blocks,
+        step_blocked_by,
+        ready,
+    }: &Instructions,
+) -> String {
+    let mut answer = String::new();
+    let mut completed = HashSet::new();
+    let mut ready = ready.clone();
+
+    while let Some(&step) = ready.iter().min() {
+        answer.push(step);
+        ready.remove(&step);
+        completed.insert(step);
+
+        let children = step_blocks
+            .get(&step)
+            .map(|c| c.as_slice())
+            .unwrap_or_default();
+        for &child in children {
+            if let Some(parents) = step_blocked_by.get(&child) {
+                if parents.iter().all(|p| completed.contains(p)) {
+                    ready.insert(child);
+                }
+            } else {
+                ready.insert(child);
+            }
+        }
+    }
+
+    answer
+}
+
+pub fn part2(instructions: &Instructions) -> usize {
+    assembly_time(instructions, 5, 60)
+}
+
+/// How long `workers` workers take to finish every step when each one takes `base` seconds
+/// plus its position in the alphabet.
+pub fn assembly_time(
+    Instructions {
+        step_blocks,
+        step_blocked_by,
+        ready,
+    }: &Instructions,
+    workers: usize,
+    base: usize,
+) -> usize {
+    let mut completed = HashSet::new();
+    let mut ready = ready.clone();
+    let mut workers: Vec<Option<Work>> = vec![None; workers];
+
+    let max_steps = step_blocks
+        .keys()
+        .chain(step_blocked_by.keys())
+        .collect::<HashSet<&char>>()
+        .len();
+
+    for t in 0.. {
+        for worker in workers.iter_mut() {
+            match worker {
+                Some(Work {
+                    step,
+                    completion_time,
+                }) if t == *completion_time => {
+                    completed.insert(*step);
+
+                    let children = step_blocks
+                        .get(step)
+                        .map(|c| c.as_slice())
+                        .unwrap_or_default();
+                    for &child in children {
+                        if let Some(parents) = step_blocked_by.get(&child) {
+                            if parents.iter().all(|p| completed.contains(p)) {
+                                ready.insert(child);
+                            }
+                        } else {
+                            ready.insert(child);
+                        }
+                    }
+                }
+                None => {}
+                _ => continue,
+            }
+
+            if let Some(&step) = ready.iter().min() {
+                ready.remove(&step);
+                let completion_time = t + completion_time(step, base);
+                *worker = Some(Work {
+                    step,
+                    completion_time,
+                });
+            } else {
+                *worker = None;
+            }
+        }
+
+        if completed.len() == max_steps {
+            return t;
+        }
+    }
+
+    unreachable!()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Instructions;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+
+    const EXAMPLE: &str = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), "CABDFE");
+    }
+
+    #[test]
+    fn assembly_time_example() {
+        assert_eq!(assembly_time(&parse(EXAMPLE).unwrap(), 2, 0), 15);
+    }
+
+    #[test]
+    fn parse_rejects_bad_step_names() {
+        assert_eq!(
+            parse("Step c must be finished before step A can begin.\n").err(),
+            Some(ParseError::new(1, 6, "step name", Some("c".to_string())))
+        );
+    }
+
+    #[test]
+    fn generated_steps_all_run() {
+        let input = parse(&generate(&mut Rng::new(1), 10)).unwrap();
+        assert_eq!(part1(&input).len(), 10);
+    }
+}
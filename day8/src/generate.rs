@@ -0,0 +1,40 @@
+use aoc_common::Rng;
+
+/// Writes `node` and its subtree. Metadata of nodes with children mostly points at one of
+/// them, so node values are rarely zero.
+fn write_node(rng: &mut Rng, node: usize, children: &[Vec<usize>], numbers: &mut Vec<usize>) {
+    let metadata = rng.range(1, 6) as usize;
+    let references = match children[node].len() {
+        0 => 9,
+        count => count + 1,
+    };
+
+    numbers.push(children[node].len());
+    numbers.push(metadata);
+    for &child in &children[node] {
+        write_node(rng, child, children, numbers);
+    }
+    for _ in 0..metadata {
+        numbers.push(1 + rng.index(references));
+    }
+}
+
+/// A license tree of `size` nodes (at least one) on a single line.
+///
+/// Each node hangs off a random earlier node, which keeps the tree shallow.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut children = vec![vec![]; size.max(1)];
+    for node in 1..children.len() {
+        let parent = rng.index(node);
+        children[parent].push(node);
+    }
+
+    let mut numbers = vec![];
+    write_node(rng, 0, &children, &mut numbers);
+
+    let numbers = numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>();
+    numbers.join(" ") + "\n"
+}
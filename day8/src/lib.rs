@@ -0,0 +1,225 @@
+use aoc_common::{ParseError, Scanner, Solution};
+
+mod generate;
+
+pub use crate::generate::generate;
+
+#[derive(Debug, PartialEq)]
+pub struct Node {
+    children: Vec<Node>,
+    metadata: Vec<usize>,
+}
+
+impl Node {
+    fn metadata_sum(&self) -> usize {
+        let mine: usize = self.metadata.iter().cloned().sum();
+        let children: usize = self.children.iter().map(|c| c.metadata_sum()).sum();
+        mine + children
+    }
+
+    fn value(&self) -> usize {
+        if self.children.is_empty() {
+            self.metadata_sum()
+        } else {
+            self.metadata
+                .iter()
+                .filter_map(|i| self.children.get(i - 1).map(|c| c.value()))
+                .sum()
+        }
+    }
+}
+
+struct Token {
+    line: usize,
+    column: usize,
+    value: usize,
+}
+
+struct Parser<I: Iterator<Item = Token>> {
+    inner: I,
+    end: (usize, usize),
+}
+
+impl<I: Iterator<Item = Token>> Parser<I> {
+    fn new<T>(iter: T, end: (usize, usize)) -> Parser<I>
+    where
+        T: IntoIterator<Item = Token, IntoIter = I>,
+    {
+        Parser {
+            inner: iter.into_iter(),
+            end,
+        }
+    }
+
+    fn next(&mut self, what: &str) -> Result<usize, ParseError> {
+        let (line, column) = self.end;
+        self.inner
+            .next()
+            .map(|token| token.value)
+            .ok_or_else(|| ParseError::new(line, column, what, None))
+    }
+
+    fn parse_node(&mut self) -> Result<Node, ParseError> {
+        let children_count = self.next("child count")?;
+        let metadata_count = self.next("metadata count")?;
+        let children = (0..children_count)
+            .map(|_| self.parse_node())
+            .collect::<Result<Vec<Node>, ParseError>>()?;
+        let metadata = (0..metadata_count)
+            .map(|_| self.next("metadata entry"))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        Ok(Node { children, metadata })
+    }
+
+    fn finish(mut self) -> Result<(), ParseError> {
+        match self.inner.next() {
+            Some(Token {
+                line,
+                column,
+                value,
+            }) => Err(ParseError::new(
+                line,
+                column,
+                "end of input",
+                Some(value.to_string()),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let mut scanner = Scanner::new(line);
+        while !scanner.is_empty() {
+            let column = scanner.column();
+            let value = scanner.integer("number").map_err(|e| e.on_line(i + 1))?;
+            tokens.push(Token {
+                line: i + 1,
+                column,
+                value,
+            });
+        }
+    }
+
+    Ok(tokens)
+}
+
+pub fn parse(input: &str) -> Result<Node, ParseError> {
+    let tokens = tokenize(input)?;
+    let end = input
+        .lines()
+        .enumerate()
+        .last()
+        .map(|(i, line)| (i + 1, line.chars().count() + 1))
+        .unwrap_or((1, 1));
+
+    let mut parser = Parser::new(tokens, end);
+    let root = parser.parse_node()?;
+    parser.finish()?;
+
+    Ok(root)
+}
+
+pub fn part1(root: &Node) -> usize {
+    root.metadata_sum()
+}
+
+pub fn part2(root: &Node) -> usize {
+    root.value()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Node;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 138);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 66);
+    }
+
+    #[test]
+    fn parse_rejects_truncated_trees() {
+        assert!(parse("2 3 0 3 10 11 12").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_trailing_numbers() {
+        assert!(parse("0 1 99 5").is_err());
+    }
+
+    #[test]
+    fn generated_tree_parses() {
+        let root = parse(&generate(&mut Rng::new(1), 100)).unwrap();
+        assert!(part1(&root) >= 100);
+    }
+
+    fn node() -> impl Strategy<Value = Node> {
+        let metadata = || prop::collection::vec(0..100usize, 0..4);
+        let leaf = metadata().prop_map(|metadata| Node {
+            children: vec![],
+            metadata,
+        });
+
+        leaf.prop_recursive(4, 32, 4, move |node| {
+            (prop::collection::vec(node, 0..4), metadata())
+                .prop_map(|(children, metadata)| Node { children, metadata })
+        })
+    }
+
+    fn encode(node: &Node, numbers: &mut Vec<String>) {
+        numbers.push(node.children.len().to_string());
+        numbers.push(node.metadata.len().to_string());
+        for child in &node.children {
+            encode(child, numbers);
+        }
+        numbers.extend(node.metadata.iter().map(|n| n.to_string()));
+    }
+
+    proptest! {
+        #[test]
+        fn tree_round_trips(root in node()) {
+            let mut numbers = vec![];
+            encode(&root, &mut numbers);
+            prop_assert_eq!(parse(&numbers.join(" ")), Ok(root));
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_common::{ParseError, Scanner, Solution};
+use std::collections::{HashMap, VecDeque};
+use tracing::{debug, debug_span};
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    players: usize,
+    marbles: usize,
+}
+
+/// How many marbles are placed between progress events.
+const PROGRESS_INTERVAL: usize = 100_000;
+
+pub fn play(players: usize, marbles: usize) -> HashMap<usize, usize> {
+    let _span = debug_span!("play", players, marbles).entered();
+    let mut scores = HashMap::new();
+    let mut circle = VecDeque::with_capacity(marbles);
+    circle.push_front(0);
+
+    for (marble, player) in (1..=marbles).zip((1..=players).cycle()) {
+        if marble % 23 == 0 {
+            let mut tail = circle.split_off(circle.len() - 7);
+            let scored = tail.pop_front().unwrap();
+
+            for &item in tail.iter().rev() {
+                circle.push_front(item);
+            }
+
+            *scores.entry(player).or_default() += marble + scored;
+        } else {
+            for _ in 0..2 {
+                let current = circle.pop_front().unwrap();
+                circle.push_back(current);
+            }
+
+            circle.push_front(marble);
+        }
+
+        if marble % PROGRESS_INTERVAL == 0 {
+            debug!(marble, circle = circle.len(), "placed marbles");
+        }
+    }
+
+    scores
+}
+
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    let line = input.lines().next().unwrap_or_default();
+    let mut scanner = Scanner::new(line);
+    let players = scanner.integer("number of players")?;
+    scanner.expect("players;")?;
+    for word in &["last", "marble", "is", "worth"] {
+        scanner.expect(word)?;
+    }
+    let marbles = scanner.integer("number of points")?;
+    scanner.expect("points")?;
+    scanner.end()?;
+
+    Ok(Game { players, marbles })
+}
+
+pub fn part1(&Game { players, marbles }: &Game) -> usize {
+    let scores = play(players, marbles);
+    *scores.values().max().unwrap()
+}
+
+pub fn part2(&Game { players, marbles }: &Game) -> usize {
+    let scores = play(players, marbles * 100);
+    *scores.values().max().unwrap()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Game;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn high_score(input: &str) -> usize {
+        part1(&parse(input).unwrap())
+    }
+
+    #[test]
+    fn play_example() {
+        assert_eq!(play(9, 25).values().max(), Some(&32));
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(
+            high_score("10 players; last marble is worth 1618 points"),
+            8317
+        );
+        assert_eq!(
+            high_score("13 players; last marble is worth 7999 points"),
+            146373
+        );
+        assert_eq!(
+            high_score("17 players; last marble is worth 1104 points"),
+            2764
+        );
+        assert_eq!(
+            high_score("21 players; last marble is worth 6111 points"),
+            54718
+        );
+        assert_eq!(
+            high_score("30 players; last marble is worth 5807 points"),
+            37305
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_games() {
+        assert_eq!(
+            parse("10 players; last marble is worth many points"),
+            Err(ParseError::new(
+                1,
+                34,
+                "number of points",
+                Some("many".to_string())
+            ))
+        );
+    }
+}
@@ -0,0 +1,9 @@
+#![no_main]
+use day10::Particle;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(particle) = input.parse::<Particle>() {
+        assert_eq!(particle.to_string().parse(), Ok(particle));
+    }
+});
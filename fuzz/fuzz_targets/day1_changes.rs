@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(changes) = day1::parse(input) {
+        let formatted = changes.iter().map(|c| format!("{:+}\n", c)).collect::<String>();
+        assert_eq!(day1::parse(&formatted), Ok(changes));
+    }
+});
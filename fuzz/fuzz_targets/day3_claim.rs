@@ -0,0 +1,9 @@
+#![no_main]
+use day3::Claim;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(claim) = input.parse::<Claim>() {
+        assert_eq!(claim.to_string().parse(), Ok(claim));
+    }
+});
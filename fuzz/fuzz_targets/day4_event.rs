@@ -0,0 +1,12 @@
+#![no_main]
+use day4::Event;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(event) = input.parse::<Event>() {
+        assert_eq!(event.to_string().parse(), Ok(event));
+    }
+
+    // Whole logs also go through sorting and replaying the events.
+    let _ = day4::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(points) = day6::parse(input) {
+        let formatted = points.iter().map(|p| format!("{}\n", p)).collect::<String>();
+        assert_eq!(day6::parse(&formatted), Ok(points));
+    }
+});